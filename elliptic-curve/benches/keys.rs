@@ -0,0 +1,84 @@
+//! Benchmarks for key types, using the mock curve.
+//!
+//! The mock curve's multiplication by the generator doesn't do a real scalar
+//! multiplication, so [`SecretKey::public_key`] only measures the cost of
+//! converting and validating the result here. On a real curve the scalar
+//! multiplication [`KeyPair`] avoids is far more expensive.
+//!
+//! The mock curve has prime order, so [`PublicKey::from_affine`] should cost
+//! the same as [`PublicKey::from_affine_unchecked`]: the subgroup check is
+//! skipped for curves with a cofactor of 1.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use elliptic_curve::{
+    dev::{MockCurve, PublicKey, SecretKey},
+    sec1::{EncodedPoint, FromEncodedPoint, ToEncodedPoint},
+    KeyPair,
+};
+use rand_core::OsRng;
+use std::hint::black_box;
+
+fn bench_public_key(c: &mut Criterion) {
+    let mut group = c.benchmark_group("public key");
+    let key_pair = KeyPair::<MockCurve>::random(&mut OsRng);
+    let secret_key: &SecretKey = key_pair.secret_key();
+
+    group.bench_function("SecretKey::public_key", |b| {
+        b.iter(|| black_box(secret_key).public_key())
+    });
+
+    group.bench_function("KeyPair::public_key", |b| {
+        b.iter(|| *black_box(&key_pair).public_key())
+    });
+
+    group.finish();
+}
+
+fn bench_from_affine(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from affine");
+    let point = *SecretKey::random(&mut OsRng).public_key().as_affine();
+
+    group.bench_function("from_affine", |b| {
+        b.iter(|| PublicKey::from_affine(black_box(point)))
+    });
+
+    group.bench_function("from_affine_unchecked", |b| {
+        b.iter(|| PublicKey::from_affine_unchecked(black_box(point)))
+    });
+
+    group.finish();
+}
+
+fn bench_batch_from_encoded_points(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode public keys");
+    let points: Vec<EncodedPoint<MockCurve>> = (0..256)
+        .map(|_| {
+            SecretKey::random(&mut OsRng)
+                .public_key()
+                .to_encoded_point(false)
+        })
+        .collect();
+
+    group.bench_function("from_encoded_point", |b| {
+        b.iter(|| {
+            black_box(&points)
+                .iter()
+                .map(|point| PublicKey::from_encoded_point(point).unwrap())
+                .collect::<Vec<_>>()
+        })
+    });
+
+    group.bench_function("batch_from_encoded_points", |b| {
+        b.iter(|| PublicKey::batch_from_encoded_points(black_box(&points)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_public_key,
+    bench_from_affine,
+    bench_batch_from_encoded_points
+);
+criterion_main!(benches);
@@ -0,0 +1,154 @@
+//! Benchmarks for the generic group algorithms in `elliptic_curve::ops`.
+//!
+//! These use the mock curve's [`ScalarGroup`], where the group operation is a
+//! scalar field addition. Its own scalar multiplication is a single field
+//! multiplication, so the baselines use [`double_and_add`] instead, which
+//! does the same sequence of group operations as the textbook scalar
+//! multiplication on a real curve. The results therefore compare algorithms
+//! by their number of group operations, not absolute performance. Note that
+//! constant-time table lookups are relatively more expensive here than on a
+//! real curve, where a group operation costs many field multiplications.
+//! The mock group is also its own affine representation, so the
+//! [`ops::sum_affine`] benchmark only shows that it adds no overhead over
+//! the naive sum; the gain from mixed addition depends on the curve.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use elliptic_curve::{
+    dev::{Scalar, ScalarGroup},
+    ff::{Field, PrimeField, PrimeFieldBits},
+    group::{Curve, Group},
+    ops,
+};
+use rand_core::OsRng;
+use std::hint::black_box;
+
+/// Textbook double-and-add scalar multiplication, used as a baseline.
+fn double_and_add<G>(point: &G, scalar: &G::Scalar) -> G
+where
+    G: Group,
+    G::Scalar: PrimeFieldBits,
+{
+    let bits = scalar.to_le_bits();
+    let mut acc = G::identity();
+
+    for bit in bits.iter().take(G::Scalar::NUM_BITS as usize).rev() {
+        acc = acc.double();
+        let sum = acc + point;
+        acc = if *bit { sum } else { acc };
+    }
+
+    acc
+}
+
+fn random_inputs(len: usize) -> (Vec<ScalarGroup>, Vec<Scalar>) {
+    let points = (0..len).map(|_| ScalarGroup::random(&mut OsRng)).collect();
+    let scalars = (0..len).map(|_| Scalar::random(&mut OsRng)).collect();
+    (points, scalars)
+}
+
+fn bench_msm(c: &mut Criterion) {
+    let mut group = c.benchmark_group("msm");
+
+    for len in [16, 64, 256] {
+        let (points, scalars) = random_inputs(len);
+
+        group.bench_with_input(BenchmarkId::new("naive", len), &len, |b, _| {
+            b.iter(|| {
+                points
+                    .iter()
+                    .zip(&scalars)
+                    .map(|(point, scalar)| double_and_add(black_box(point), black_box(scalar)))
+                    .sum::<ScalarGroup>()
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("msm", len), &len, |b, _| {
+            b.iter(|| ops::msm(black_box(&points), black_box(&scalars)))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_basepoint_table(c: &mut Criterion) {
+    let mut group = c.benchmark_group("basepoint table");
+    let generator = ScalarGroup::generator();
+    let scalar = Scalar::random(&mut OsRng);
+
+    group.bench_function("double-and-add", |b| {
+        b.iter(|| double_and_add(black_box(&generator), black_box(&scalar)))
+    });
+
+    let table = ops::BasepointTable::<_, 4>::new(generator);
+    group.bench_function("W = 4", |b| b.iter(|| table.mul(black_box(&scalar))));
+
+    let table = ops::BasepointTable::<_, 6>::new(generator);
+    group.bench_function("W = 6", |b| b.iter(|| table.mul(black_box(&scalar))));
+
+    group.finish();
+}
+
+fn bench_wnaf(c: &mut Criterion) {
+    let mut group = c.benchmark_group("wnaf");
+    let point = ScalarGroup::random(&mut OsRng);
+    let scalar = Scalar::random(&mut OsRng);
+
+    group.bench_function("double-and-add", |b| {
+        b.iter(|| double_and_add(black_box(&point), black_box(&scalar)))
+    });
+
+    for window in [4, 5, 8] {
+        group.bench_with_input(BenchmarkId::new("window", window), &window, |b, &window| {
+            b.iter(|| ops::mul_wnaf_vartime(black_box(&point), black_box(&scalar), window))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_double_base(c: &mut Criterion) {
+    let mut group = c.benchmark_group("double base");
+    let generator = ScalarGroup::generator();
+    let point = ScalarGroup::random(&mut OsRng);
+    let a = Scalar::random(&mut OsRng);
+    let b = Scalar::random(&mut OsRng);
+
+    group.bench_function("double-and-add", |bench| {
+        bench.iter(|| {
+            double_and_add(black_box(&generator), black_box(&a))
+                + double_and_add(black_box(&point), black_box(&b))
+        })
+    });
+
+    group.bench_function("mul_double_base_vartime", |bench| {
+        bench.iter(|| ops::mul_double_base_vartime(black_box(&a), black_box(&point), black_box(&b)))
+    });
+
+    group.finish();
+}
+
+fn bench_sum_affine(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum affine");
+    let (points, _) = random_inputs(1000);
+    let points = points.iter().map(Curve::to_affine).collect::<Vec<_>>();
+
+    group.bench_function("naive", |b| {
+        b.iter(|| black_box(&points).iter().copied().sum::<ScalarGroup>())
+    });
+
+    group.bench_function("sum_affine", |b| {
+        b.iter(|| ops::sum_affine::<ScalarGroup>(black_box(&points)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_msm,
+    bench_basepoint_table,
+    bench_wnaf,
+    bench_double_base,
+    bench_sum_affine
+);
+criterion_main!(benches);
@@ -0,0 +1,130 @@
+//! Benchmarks for `ScalarPrimitive` arithmetic and field inversion, using the
+//! mock curve.
+//!
+//! The generic field algorithms are benchmarked on the mock base field
+//! [`FieldElement`], which uses Montgomery arithmetic like a real curve. The
+//! mock [`Scalar`](elliptic_curve::dev::Scalar) multiplies with a full modular
+//! reduction that costs about half an inversion, which would make any
+//! algorithm trading inversions for multiplications look slow.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use elliptic_curve::{
+    dev::{FieldElement, ScalarPrimitive},
+    ff::Field,
+    ops::{BatchInvertInPlace, Invert},
+};
+use rand_core::OsRng;
+use std::hint::black_box;
+
+fn random_scalars(len: usize) -> Vec<ScalarPrimitive> {
+    (0..len)
+        .map(|_| ScalarPrimitive::random_nonzero(&mut OsRng))
+        .collect()
+}
+
+fn bench_batch_invert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch invert");
+
+    for len in [16, 256] {
+        let scalars = random_scalars(len);
+
+        group.bench_with_input(BenchmarkId::new("naive", len), &len, |b, _| {
+            b.iter(|| {
+                black_box(&scalars)
+                    .iter()
+                    .map(|scalar| scalar.invert().unwrap())
+                    .collect::<Vec<_>>()
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("batch_invert", len), &len, |b, _| {
+            b.iter(|| {
+                let mut scalars = black_box(&scalars).clone();
+                ScalarPrimitive::batch_invert(&mut scalars);
+                scalars
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_montgomery(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sequential mul");
+    let a = ScalarPrimitive::random(&mut OsRng);
+    let b = ScalarPrimitive::random(&mut OsRng);
+
+    for n in [1, 2, 4, 16, 64] {
+        group.bench_with_input(BenchmarkId::new("ScalarPrimitive", n), &n, |bench, &n| {
+            bench.iter(|| (0..n).fold(black_box(b), |acc, _| acc * black_box(a)))
+        });
+
+        // Includes the conversions into and out of Montgomery form
+        group.bench_with_input(BenchmarkId::new("Montgomery", n), &n, |bench, &n| {
+            bench.iter(|| {
+                let a = black_box(a).to_montgomery();
+                let acc = (0..n).fold(black_box(b).to_montgomery(), |acc, _| acc * &a);
+                ScalarPrimitive::from_montgomery(&acc)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_batch_invert_in_place(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch invert in place");
+
+    for len in [16, 256] {
+        let elems: Vec<FieldElement> = (0..len).map(|_| FieldElement::random(&mut OsRng)).collect();
+
+        group.bench_with_input(BenchmarkId::new("naive", len), &len, |b, _| {
+            b.iter(|| {
+                black_box(&elems)
+                    .iter()
+                    .map(|elem| Invert::invert(elem).unwrap())
+                    .collect::<Vec<_>>()
+            })
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("batch_invert_in_place", len),
+            &len,
+            |b, _| {
+                b.iter(|| {
+                    let mut elems = black_box(&elems).clone();
+                    FieldElement::batch_invert_in_place(&mut elems);
+                    elems
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_lincomb(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lincomb");
+    let [a, b, x, y] = [(); 4].map(|_| ScalarPrimitive::random(&mut OsRng));
+
+    group.bench_function("naive", |bench| {
+        bench.iter(|| black_box(a) * black_box(b) + black_box(x) * black_box(y))
+    });
+
+    group.bench_function("lincomb", |bench| {
+        bench.iter(|| {
+            ScalarPrimitive::lincomb(black_box(&a), black_box(&b), black_box(&x), black_box(&y))
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_batch_invert,
+    bench_lincomb,
+    bench_batch_invert_in_place,
+    bench_montgomery
+);
+criterion_main!(benches);
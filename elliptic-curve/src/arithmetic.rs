@@ -1,13 +1,13 @@
 //! Elliptic curve arithmetic traits.
 
 use crate::{
-    ops::{Invert, LinearCombination, MulByGenerator, Reduce, ShrAssign},
+    ops::{self, Invert, LinearCombination, MulByGenerator, Reduce, ShrAssign},
     point::AffineCoordinates,
-    scalar::{FromUintUnchecked, IsHigh},
+    scalar::{FromUint, IsHigh},
     Curve, FieldBytes, PrimeCurve, ScalarPrimitive,
 };
 use core::fmt::Debug;
-use subtle::{ConditionallySelectable, ConstantTimeEq, CtOption};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 use zeroize::DefaultIsZeroes;
 
 /// Elliptic curve with an arithmetic implementation.
@@ -65,7 +65,7 @@ pub trait CurveArithmetic: Curve {
     type Scalar: AsRef<Self::Scalar>
         + DefaultIsZeroes
         + From<ScalarPrimitive<Self>>
-        + FromUintUnchecked<Uint = Self::Uint>
+        + FromUint<Uint = Self::Uint>
         + Into<FieldBytes<Self>>
         + Into<ScalarPrimitive<Self>>
         + Into<Self::Uint>
@@ -76,6 +76,19 @@ pub trait CurveArithmetic: Curve {
         + ShrAssign<usize>
         + ff::Field
         + ff::PrimeField<Repr = FieldBytes<Self>>;
+
+    /// Is the given point in the prime order subgroup, i.e. is it free of any
+    /// small order component?
+    ///
+    /// This is used to validate [`PublicKey`][`crate::PublicKey`]s. The
+    /// default implementation is the same as
+    /// [`ClearCofactor::is_torsion_free`][`ops::ClearCofactor::is_torsion_free`]:
+    /// a no-op for curves with a cofactor of one, and a check that
+    /// `(n - 1) * P + P` is the identity otherwise, which curves with a
+    /// cofactor will typically want to override with a faster check.
+    fn is_torsion_free(point: &Self::ProjectivePoint) -> Choice {
+        ops::is_torsion_free::<Self, Self::ProjectivePoint>(point)
+    }
 }
 
 /// Prime order elliptic curve with projective arithmetic implementation.
@@ -85,3 +98,83 @@ pub trait PrimeCurveArithmetic:
     /// Prime order elliptic curve group.
     type CurveGroup: group::prime::PrimeCurve<Affine = <Self as CurveArithmetic>::AffinePoint>;
 }
+
+/// Parameters of a short Weierstrass curve `y² = x³ + ax + b` over a prime
+/// field, allowing points to be checked against the curve equation
+/// generically.
+pub trait PrimeCurveParams: CurveArithmetic {
+    /// Base field element type.
+    type FieldElement: ff::PrimeField<Repr = FieldBytes<Self>>;
+
+    /// Coefficient `a` in the curve equation.
+    const EQUATION_A: Self::FieldElement;
+
+    /// Coefficient `b` in the curve equation.
+    const EQUATION_B: Self::FieldElement;
+
+    /// Is the point with the given affine coordinates on the curve?
+    fn is_on_curve(x: &Self::FieldElement, y: &Self::FieldElement) -> Choice {
+        let rhs = (ff::Field::square(x) + Self::EQUATION_A) * x + Self::EQUATION_B;
+        ff::Field::square(y).ct_eq(&rhs)
+    }
+}
+
+/// Recover the `y`-coordinate with the given parity of the point on the curve
+/// `C` whose `x`-coordinate is the big endian serialized field element `x`.
+///
+/// This computes `y = sqrt(x³ + ax + b)` as `(x³ + ax + b)^((p + 1) / 4)`,
+/// which is only a square root when the base field modulus `p ≡ 3 mod 4`
+/// (e.g. P-256, P-384, P-521, secp256k1). It is intended as a building block
+/// for [`DecompressPoint`][`crate::point::DecompressPoint`] impls of such
+/// curves.
+///
+/// Returns a none [`CtOption`] if `x` is not a canonical field element, or if
+/// `x³ + ax + b` is not a quadratic residue, i.e. `x` is not the
+/// `x`-coordinate of any point on the curve. The candidate root is always
+/// checked, so this never returns an incorrect `y`, even if `p ≢ 3 mod 4`.
+///
+/// The running time depends only on the modulus, not on `x` or `y_is_odd`.
+///
+/// The exponent is derived from the field's [`PrimeField::Repr`], which must be
+/// big endian (as it is for the curves above), since each curve chooses its
+/// own endianness. With a little endian `Repr` the exponent is wrong, so the
+/// result is none for almost every input.
+///
+/// [`PrimeField::Repr`]: ff::PrimeField::Repr
+pub fn decompress_y_p3mod4<C>(x: &FieldBytes<C>, y_is_odd: Choice) -> CtOption<C::FieldElement>
+where
+    C: PrimeCurveParams,
+{
+    use ff::{Field, PrimeField};
+
+    // `p - 1`, big endian
+    let mut exp = (-C::FieldElement::ONE).to_repr();
+
+    // `(p + 1) / 4 = ((p - 1) >> 2) + 1`, so compute `rhs^((p - 1) >> 2) * rhs`
+    let mut carry = 0u8;
+    for byte in exp.iter_mut() {
+        let next_carry = *byte << 6;
+        *byte = (*byte >> 2) | carry;
+        carry = next_carry;
+    }
+
+    C::FieldElement::from_repr(x.clone()).and_then(|x| {
+        let rhs = (x.square() + C::EQUATION_A) * x + C::EQUATION_B;
+
+        // The exponent is a public curve constant, so branching on its bits
+        // doesn't leak anything about `x`.
+        let mut y = C::FieldElement::ONE;
+        for byte in exp.iter() {
+            for i in (0..8).rev() {
+                y = y.square();
+                if (byte >> i) & 1 == 1 {
+                    y *= rhs;
+                }
+            }
+        }
+        y *= rhs;
+
+        let y = C::FieldElement::conditional_select(&-y, &y, y.is_odd().ct_eq(&y_is_odd));
+        CtOption::new(y, y.square().ct_eq(&rhs))
+    })
+}
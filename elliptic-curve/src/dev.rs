@@ -5,18 +5,28 @@
 
 use crate::{
     array::typenum::U32,
-    bigint::{Limb, U256},
+    bigint::{modular::ConstMontyForm, ArrayEncoding, Encoding, Integer, Limb, U256, U512},
+    decompress_y_p3mod4,
     error::{Error, Result},
-    ops::{Invert, LinearCombination, MulByGenerator, Reduce, ShrAssign},
+    ops::{
+        BatchInvertInPlace, ClearCofactor, Invert, LinearCombination, LinearCombinationExt,
+        MulByGenerator, Reduce, ReduceNonZero, ShrAssign,
+    },
     pkcs8,
-    point::AffineCoordinates,
-    rand_core::RngCore,
+    point::{
+        AffineCoordinates, BatchNormalize, DecompactPoint, DecompressPoint, PointCompaction,
+        PointCompression,
+    },
+    rand_core::{CryptoRng, RngCore},
     scalar::{FromUintUnchecked, IsHigh},
-    sec1::{CompressedPoint, FromEncodedPoint, ToEncodedPoint},
-    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
+    sec1::{
+        CompressedPoint, Coordinates, FromEncodedPoint, Tag, ToCompactEncodedPoint, ToEncodedPoint,
+    },
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess, CtOption},
     zeroize::DefaultIsZeroes,
-    Curve, CurveArithmetic, FieldBytesEncoding, PrimeCurve,
+    Curve, CurveArithmetic, FieldBytesEncoding, PrimeCurve, PrimeCurveParams,
 };
+use alloc::vec::Vec;
 use core::{
     iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
@@ -28,9 +38,20 @@ use pkcs8::AssociatedOid;
 #[cfg(feature = "bits")]
 use ff::PrimeFieldBits;
 
+#[cfg(feature = "precomputed-tables")]
+use crate::ops::LazyBasepointTable;
+
 #[cfg(feature = "jwk")]
 use crate::JwkParameters;
 
+/// Affine x-coordinate of the generator.
+const GENERATOR_X: [u8; 32] =
+    hex!("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296");
+
+/// Affine y-coordinate of the generator.
+const GENERATOR_Y: [u8; 32] =
+    hex!("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5");
+
 /// Pseudo-coordinate for fixed-based scalar mult output
 pub const PSEUDO_COORDINATE_FIXED_BASE_MUL: [u8; 32] =
     hex!("deadbeef00000000000000000000000000000000000000000000000000000001");
@@ -41,9 +62,15 @@ pub type EncodedPoint = crate::sec1::EncodedPoint<MockCurve>;
 /// Field element bytes.
 pub type FieldBytes = crate::FieldBytes<MockCurve>;
 
+/// Key pair.
+pub type KeyPair = crate::KeyPair<MockCurve>;
+
 /// Non-zero scalar value.
 pub type NonZeroScalar = crate::NonZeroScalar<MockCurve>;
 
+/// Non-zero scalar primitive type.
+pub type NonZeroScalarPrimitive = crate::NonZeroScalarPrimitive<MockCurve>;
+
 /// Public key.
 pub type PublicKey = crate::PublicKey<MockCurve>;
 
@@ -82,6 +109,25 @@ impl CurveArithmetic for MockCurve {
     type Scalar = Scalar;
 }
 
+impl PrimeCurveParams for MockCurve {
+    type FieldElement = FieldElement;
+
+    const EQUATION_A: FieldElement = FieldElement::from_be_hex(
+        "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc",
+    );
+    const EQUATION_B: FieldElement = FieldElement::from_be_hex(
+        "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+    );
+}
+
+impl PointCompression for MockCurve {
+    const COMPRESS_POINTS: bool = false;
+}
+
+impl PointCompaction for MockCurve {
+    const COMPACT_POINTS: bool = false;
+}
+
 impl AssociatedOid for MockCurve {
     /// OID for NIST P-256
     const OID: pkcs8::ObjectIdentifier = pkcs8::ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
@@ -92,6 +138,52 @@ impl JwkParameters for MockCurve {
     const CRV: &'static str = "P-256";
 }
 
+/// Variant of [`MockCurve`] with a configurable cofactor and point
+/// compression, useful for testing code whose behavior depends on them.
+///
+/// It shares the order, points, and scalars of [`MockCurve`]. The mock
+/// arithmetic can't multiply arbitrary points, so when `COFACTOR` isn't 1 the
+/// prime order subgroup is modeled as the points derived from the generator,
+/// and any other point is treated as having a small order component.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct MockCurveVariant<const COFACTOR: u64, const COMPRESS_POINTS: bool>;
+
+impl<const COFACTOR: u64, const COMPRESS_POINTS: bool> Curve
+    for MockCurveVariant<COFACTOR, COMPRESS_POINTS>
+{
+    type FieldBytesSize = U32;
+    type Uint = U256;
+
+    const ORDER: U256 = MockCurve::ORDER;
+    const COFACTOR: u64 = COFACTOR;
+}
+
+impl<const COFACTOR: u64, const COMPRESS_POINTS: bool> CurveArithmetic
+    for MockCurveVariant<COFACTOR, COMPRESS_POINTS>
+{
+    type AffinePoint = AffinePoint;
+    type ProjectivePoint = ProjectivePoint;
+    type Scalar = Scalar;
+
+    fn is_torsion_free(point: &ProjectivePoint) -> Choice {
+        Choice::from(u8::from(
+            COFACTOR == 1 || !matches!(point, ProjectivePoint::Other(_)),
+        ))
+    }
+}
+
+impl<const COFACTOR: u64, const COMPRESS_POINTS: bool> PointCompression
+    for MockCurveVariant<COFACTOR, COMPRESS_POINTS>
+{
+    const COMPRESS_POINTS: bool = COMPRESS_POINTS;
+}
+
+impl<const COFACTOR: u64, const COMPRESS_POINTS: bool> AssociatedOid
+    for MockCurveVariant<COFACTOR, COMPRESS_POINTS>
+{
+    const OID: pkcs8::ObjectIdentifier = MockCurve::OID;
+}
+
 /// Example scalar type
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Scalar(ScalarPrimitive);
@@ -117,7 +209,7 @@ impl Field for Scalar {
 
     #[must_use]
     fn square(&self) -> Self {
-        unimplemented!();
+        self.mul(self)
     }
 
     #[must_use]
@@ -126,11 +218,11 @@ impl Field for Scalar {
     }
 
     fn invert(&self) -> CtOption<Self> {
-        unimplemented!();
+        self.0.invert().map(Self)
     }
 
     fn sqrt(&self) -> CtOption<Self> {
-        unimplemented!();
+        self.0.sqrt().map(Self)
     }
 
     fn sqrt_ratio(_num: &Self, _div: &Self) -> (Choice, Self) {
@@ -261,28 +353,28 @@ impl SubAssign<&Scalar> for Scalar {
 impl Mul<Scalar> for Scalar {
     type Output = Scalar;
 
-    fn mul(self, _other: Scalar) -> Scalar {
-        unimplemented!();
+    fn mul(self, other: Scalar) -> Scalar {
+        self.mul(&other)
     }
 }
 
 impl Mul<&Scalar> for Scalar {
     type Output = Scalar;
 
-    fn mul(self, _other: &Scalar) -> Scalar {
-        unimplemented!();
+    fn mul(self, other: &Scalar) -> Scalar {
+        Self(self.0.mul(&other.0))
     }
 }
 
 impl MulAssign<Scalar> for Scalar {
-    fn mul_assign(&mut self, _rhs: Scalar) {
-        unimplemented!();
+    fn mul_assign(&mut self, rhs: Scalar) {
+        *self = *self * rhs;
     }
 }
 
 impl MulAssign<&Scalar> for Scalar {
-    fn mul_assign(&mut self, _rhs: &Scalar) {
-        unimplemented!();
+    fn mul_assign(&mut self, rhs: &Scalar) {
+        *self = *self * rhs;
     }
 }
 
@@ -294,6 +386,14 @@ impl Neg for Scalar {
     }
 }
 
+impl Neg for &Scalar {
+    type Output = Scalar;
+
+    fn neg(self) -> Scalar {
+        -*self
+    }
+}
+
 impl ShrAssign<usize> for Scalar {
     fn shr_assign(&mut self, rhs: usize) {
         self.0 >>= rhs;
@@ -301,34 +401,36 @@ impl ShrAssign<usize> for Scalar {
 }
 
 impl Sum for Scalar {
-    fn sum<I: Iterator<Item = Self>>(_iter: I) -> Self {
-        unimplemented!();
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|scalar| scalar.0).sum())
     }
 }
 
 impl<'a> Sum<&'a Scalar> for Scalar {
-    fn sum<I: Iterator<Item = &'a Scalar>>(_iter: I) -> Self {
-        unimplemented!();
+    fn sum<I: Iterator<Item = &'a Scalar>>(iter: I) -> Self {
+        iter.copied().sum()
     }
 }
 
 impl Product for Scalar {
-    fn product<I: Iterator<Item = Self>>(_iter: I) -> Self {
-        unimplemented!();
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|scalar| scalar.0).product())
     }
 }
 
 impl<'a> Product<&'a Scalar> for Scalar {
-    fn product<I: Iterator<Item = &'a Scalar>>(_iter: I) -> Self {
-        unimplemented!();
+    fn product<I: Iterator<Item = &'a Scalar>>(iter: I) -> Self {
+        iter.copied().product()
     }
 }
 
+impl BatchInvertInPlace for Scalar {}
+
 impl Invert for Scalar {
     type Output = CtOption<Scalar>;
 
     fn invert(&self) -> CtOption<Scalar> {
-        unimplemented!();
+        Field::invert(self)
     }
 }
 
@@ -347,26 +449,105 @@ impl Reduce<U256> for Scalar {
     }
 }
 
+impl Reduce<U512> for Scalar {
+    type Bytes = <U512 as Encoding>::Repr;
+
+    fn reduce(w: U512) -> Self {
+        Self(ScalarPrimitive::reduce(w))
+    }
+
+    fn reduce_bytes(bytes: &Self::Bytes) -> Self {
+        Self(ScalarPrimitive::reduce_bytes(bytes))
+    }
+}
+
+impl ReduceNonZero<U512> for Scalar {
+    fn reduce_nonzero(w: U512) -> Self {
+        Self(ScalarPrimitive::reduce_nonzero(w))
+    }
+
+    fn reduce_nonzero_bytes(bytes: &Self::Bytes) -> Self {
+        Self(ScalarPrimitive::reduce_nonzero_bytes(bytes))
+    }
+}
+
 impl FieldBytesEncoding<MockCurve> for U256 {}
 
+impl<const COFACTOR: u64, const COMPRESS_POINTS: bool>
+    FieldBytesEncoding<MockCurveVariant<COFACTOR, COMPRESS_POINTS>> for U256
+{
+}
+
+impl From<u8> for Scalar {
+    fn from(n: u8) -> Scalar {
+        Self(n.into())
+    }
+}
+
+impl From<u16> for Scalar {
+    fn from(n: u16) -> Scalar {
+        Self(n.into())
+    }
+}
+
+impl From<u32> for Scalar {
+    fn from(n: u32) -> Scalar {
+        Self(n.into())
+    }
+}
+
 impl From<u64> for Scalar {
     fn from(n: u64) -> Scalar {
         Self(n.into())
     }
 }
 
+impl From<u128> for Scalar {
+    fn from(n: u128) -> Scalar {
+        Self(n.into())
+    }
+}
+
 impl From<ScalarPrimitive> for Scalar {
     fn from(scalar: ScalarPrimitive) -> Scalar {
         Self(scalar)
     }
 }
 
+impl From<&ScalarPrimitive> for Scalar {
+    fn from(scalar: &ScalarPrimitive) -> Scalar {
+        Self(*scalar)
+    }
+}
+
 impl From<Scalar> for ScalarPrimitive {
     fn from(scalar: Scalar) -> ScalarPrimitive {
         scalar.0
     }
 }
 
+impl<const COFACTOR: u64, const COMPRESS_POINTS: bool>
+    From<crate::ScalarPrimitive<MockCurveVariant<COFACTOR, COMPRESS_POINTS>>> for Scalar
+{
+    fn from(scalar: crate::ScalarPrimitive<MockCurveVariant<COFACTOR, COMPRESS_POINTS>>) -> Scalar {
+        Self::from_uint_unchecked(*scalar.as_uint())
+    }
+}
+
+impl<const COFACTOR: u64, const COMPRESS_POINTS: bool> From<Scalar>
+    for crate::ScalarPrimitive<MockCurveVariant<COFACTOR, COMPRESS_POINTS>>
+{
+    fn from(scalar: Scalar) -> Self {
+        Self::from_uint_unchecked(*scalar.0.as_uint())
+    }
+}
+
+impl From<&Scalar> for ScalarPrimitive {
+    fn from(scalar: &Scalar) -> ScalarPrimitive {
+        scalar.0
+    }
+}
+
 impl From<Scalar> for U256 {
     fn from(scalar: Scalar) -> U256 {
         scalar.0.to_uint()
@@ -381,6 +562,8 @@ impl TryFrom<U256> for Scalar {
     }
 }
 
+/// [`FromUint`][`crate::scalar::FromUint`] is provided by the blanket impl
+/// for fields, as it is for curve implementations.
 impl FromUintUnchecked for Scalar {
     type Uint = U256;
 
@@ -407,6 +590,259 @@ impl IsHigh for Scalar {
     }
 }
 
+mod field_modulus {
+    #![allow(missing_docs)]
+    use crate::bigint::{impl_modulus, U256};
+
+    impl_modulus!(
+        FieldModulus,
+        U256,
+        "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+    );
+}
+
+type FieldMontyForm = ConstMontyForm<field_modulus::FieldModulus, { U256::LIMBS }>;
+
+/// Example base field element type (the P-256 base field)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FieldElement(FieldMontyForm);
+
+impl FieldElement {
+    /// Base field modulus
+    pub const MODULUS: U256 =
+        U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+
+    /// Create a field element from a big endian hex string, which must be
+    /// less than the modulus.
+    pub const fn from_be_hex(hex: &str) -> Self {
+        Self(FieldMontyForm::new(&U256::from_be_hex(hex)))
+    }
+}
+
+impl Field for FieldElement {
+    const ZERO: Self = Self(FieldMontyForm::ZERO);
+    const ONE: Self = Self(FieldMontyForm::ONE);
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let mut bytes = FieldBytes::default();
+
+        loop {
+            rng.fill_bytes(&mut bytes);
+            if let Some(fe) = Self::from_repr(bytes).into() {
+                return fe;
+            }
+        }
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.ct_eq(&Self::ZERO)
+    }
+
+    fn square(&self) -> Self {
+        Self(self.0.square())
+    }
+
+    fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        CtOption::from(self.0.inv()).map(Self)
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        // `p ≡ 3 mod 4`, so `sqrt(x) = x^((p + 1) / 4)`
+        let sqrt = Self(self.0.pow(&U256::from_be_hex(
+            "3fffffffc0000000400000000000000000000000400000000000000000000000",
+        )));
+        CtOption::new(sqrt, sqrt.square().ct_eq(self))
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        ff::helpers::sqrt_ratio_generic(num, div)
+    }
+}
+
+impl PrimeField for FieldElement {
+    type Repr = FieldBytes;
+
+    const MODULUS: &'static str =
+        "0xffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+    const NUM_BITS: u32 = 256;
+    const CAPACITY: u32 = 255;
+    const TWO_INV: Self = Self::ZERO; // BOGUS!
+    const MULTIPLICATIVE_GENERATOR: Self = Self::ZERO; // BOGUS!
+    const S: u32 = 1;
+    const ROOT_OF_UNITY: Self = Self::ZERO; // BOGUS!
+    const ROOT_OF_UNITY_INV: Self = Self::ZERO; // BOGUS!
+    const DELTA: Self = Self::ZERO; // BOGUS!
+
+    fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
+        let uint = U256::from_be_byte_array(bytes);
+        CtOption::new(Self(FieldMontyForm::new(&uint)), uint.ct_lt(&Self::MODULUS))
+    }
+
+    fn to_repr(&self) -> FieldBytes {
+        self.0.retrieve().to_be_byte_array()
+    }
+
+    fn is_odd(&self) -> Choice {
+        Integer::is_odd(&self.0.retrieve())
+    }
+}
+
+impl ConditionallySelectable for FieldElement {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(FieldMontyForm::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl ConstantTimeEq for FieldElement {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl Default for FieldElement {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl DefaultIsZeroes for FieldElement {}
+
+impl From<u64> for FieldElement {
+    fn from(n: u64) -> FieldElement {
+        Self(FieldMontyForm::new(&U256::from(n)))
+    }
+}
+
+impl Add<FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn add(self, other: FieldElement) -> FieldElement {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Add<&FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn add(self, other: &FieldElement) -> FieldElement {
+        Self(self.0 + other.0)
+    }
+}
+
+impl AddAssign<FieldElement> for FieldElement {
+    fn add_assign(&mut self, other: FieldElement) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<&FieldElement> for FieldElement {
+    fn add_assign(&mut self, other: &FieldElement) {
+        *self = *self + other;
+    }
+}
+
+impl Sub<FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn sub(self, other: FieldElement) -> FieldElement {
+        Self(self.0 - other.0)
+    }
+}
+
+impl Sub<&FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn sub(self, other: &FieldElement) -> FieldElement {
+        Self(self.0 - other.0)
+    }
+}
+
+impl SubAssign<FieldElement> for FieldElement {
+    fn sub_assign(&mut self, other: FieldElement) {
+        *self = *self - other;
+    }
+}
+
+impl SubAssign<&FieldElement> for FieldElement {
+    fn sub_assign(&mut self, other: &FieldElement) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn mul(self, other: FieldElement) -> FieldElement {
+        Self(self.0 * other.0)
+    }
+}
+
+impl Mul<&FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn mul(self, other: &FieldElement) -> FieldElement {
+        Self(self.0 * other.0)
+    }
+}
+
+impl MulAssign<FieldElement> for FieldElement {
+    fn mul_assign(&mut self, other: FieldElement) {
+        *self = *self * other;
+    }
+}
+
+impl MulAssign<&FieldElement> for FieldElement {
+    fn mul_assign(&mut self, other: &FieldElement) {
+        *self = *self * other;
+    }
+}
+
+impl Neg for FieldElement {
+    type Output = FieldElement;
+
+    fn neg(self) -> FieldElement {
+        Self(-self.0)
+    }
+}
+
+impl Sum for FieldElement {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a FieldElement> for FieldElement {
+    fn sum<I: Iterator<Item = &'a FieldElement>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for FieldElement {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl<'a> Product<&'a FieldElement> for FieldElement {
+    fn product<I: Iterator<Item = &'a FieldElement>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl BatchInvertInPlace for FieldElement {}
+
+impl Invert for FieldElement {
+    type Output = CtOption<FieldElement>;
+
+    fn invert(&self) -> CtOption<FieldElement> {
+        Field::invert(self)
+    }
+}
+
 /// Example affine point type
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum AffinePoint {
@@ -423,15 +859,51 @@ pub enum AffinePoint {
     Other(EncodedPoint),
 }
 
+impl AffinePoint {
+    /// Recover the y-coordinate of the point with the given x-coordinate
+    /// from the curve equation.
+    fn decompress_y(x: &FieldBytes, y_is_odd: Choice) -> CtOption<FieldElement> {
+        decompress_y_p3mod4::<MockCurve>(x, y_is_odd)
+    }
+}
+
 impl AffineCoordinates for AffinePoint {
     type FieldRepr = FieldBytes;
 
     fn x(&self) -> FieldBytes {
-        unimplemented!();
+        match self {
+            Self::FixedBaseOutput(scalar) => scalar.to_repr(),
+            Self::Identity => FieldBytes::default(),
+            Self::Generator => GENERATOR_X.into(),
+            Self::Other(point) => point.x().copied().unwrap_or_default(),
+        }
+    }
+
+    fn y(&self) -> FieldBytes {
+        match self {
+            Self::FixedBaseOutput(_) => PSEUDO_COORDINATE_FIXED_BASE_MUL.into(),
+            Self::Identity => FieldBytes::default(),
+            Self::Generator => GENERATOR_Y.into(),
+            Self::Other(point) => match point.coordinates() {
+                Coordinates::Identity => FieldBytes::default(),
+                Coordinates::Compact { x } => {
+                    // Compact points use the smaller of `y` and `-y`
+                    let y = Option::from(Self::decompress_y(x, Choice::from(0)))
+                        .map(|y: FieldElement| core::cmp::min(y.to_repr(), (-y).to_repr()));
+                    y.unwrap_or_default()
+                }
+                Coordinates::Compressed { x, y_is_odd } => {
+                    Option::from(Self::decompress_y(x, Choice::from(u8::from(y_is_odd))))
+                        .map(|y: FieldElement| y.to_repr())
+                        .unwrap_or_default()
+                }
+                Coordinates::Uncompressed { y, .. } => *y,
+            },
+        }
     }
 
     fn y_is_odd(&self) -> Choice {
-        unimplemented!();
+        Choice::from(self.y()[31] & 1)
     }
 }
 
@@ -467,15 +939,64 @@ impl Default for AffinePoint {
 
 impl DefaultIsZeroes for AffinePoint {}
 
+impl DecompressPoint<MockCurve> for AffinePoint {
+    fn decompress(x: &FieldBytes, y_is_odd: Choice) -> CtOption<Self> {
+        Self::decompress_y(x, y_is_odd).map(|y| {
+            Self::Other(EncodedPoint::from_affine_coordinates(
+                x,
+                &y.to_repr(),
+                false,
+            ))
+        })
+    }
+}
+
+/// Compact points use the smaller of `y` and `-y`.
+impl DecompactPoint<MockCurve> for AffinePoint {
+    fn decompact(x: &FieldBytes) -> CtOption<Self> {
+        Self::decompress_y(x, Choice::from(0)).map(|y| {
+            let y = core::cmp::min(y.to_repr(), (-y).to_repr());
+            Self::Other(EncodedPoint::from_affine_coordinates(x, &y, false))
+        })
+    }
+}
+
+impl ToCompactEncodedPoint<MockCurve> for AffinePoint {
+    fn to_compact_encoded_point(&self) -> CtOption<EncodedPoint> {
+        let x = self.x();
+
+        Self::decompact(&x).and_then(|point| {
+            let mut bytes = [0u8; 33];
+            bytes[0] = Tag::Compact.into();
+            bytes[1..].copy_from_slice(&x);
+
+            let compact = EncodedPoint::from_bytes(bytes).expect("compact point");
+            let is_identity = Choice::from(u8::from(*self == Self::Identity));
+            CtOption::new(compact, point.y().ct_eq(&self.y()) & !is_identity)
+        })
+    }
+}
+
+impl<const COFACTOR: u64, const COMPRESS_POINTS: bool>
+    ToCompactEncodedPoint<MockCurveVariant<COFACTOR, COMPRESS_POINTS>> for AffinePoint
+{
+    fn to_compact_encoded_point(
+        &self,
+    ) -> CtOption<crate::sec1::EncodedPoint<MockCurveVariant<COFACTOR, COMPRESS_POINTS>>> {
+        ToCompactEncodedPoint::<MockCurve>::to_compact_encoded_point(self)
+    }
+}
+
 impl FromEncodedPoint<MockCurve> for AffinePoint {
     fn from_encoded_point(encoded_point: &EncodedPoint) -> CtOption<Self> {
-        let point = if encoded_point.is_identity() {
-            Self::Identity
-        } else {
-            Self::Other(*encoded_point)
-        };
-
-        CtOption::new(point, Choice::from(1))
+        match encoded_point.coordinates() {
+            Coordinates::Identity => CtOption::new(Self::Identity, Choice::from(1)),
+            Coordinates::Compact { x } => Self::decompact(x),
+            Coordinates::Compressed { x, y_is_odd } => {
+                Self::decompress(x, Choice::from(u8::from(y_is_odd)))
+            }
+            _ => CtOption::new(Self::Other(*encoded_point), Choice::from(1)),
+        }
     }
 }
 
@@ -487,14 +1008,9 @@ impl ToEncodedPoint<MockCurve> for AffinePoint {
                 &PSEUDO_COORDINATE_FIXED_BASE_MUL.into(),
                 false,
             ),
-            Self::Other(point) => {
-                if compress == point.is_compressed() {
-                    *point
-                } else {
-                    unimplemented!();
-                }
-            }
-            _ => unimplemented!(),
+            Self::Identity => EncodedPoint::identity(),
+            Self::Other(point) if compress == point.is_compressed() => *point,
+            _ => EncodedPoint::from_affine_coordinates(&self.x(), &self.y(), compress),
         }
     }
 }
@@ -571,6 +1087,53 @@ impl From<ProjectivePoint> for AffinePoint {
     }
 }
 
+impl From<PublicKey> for AffinePoint {
+    fn from(public_key: PublicKey) -> AffinePoint {
+        *public_key.as_affine()
+    }
+}
+
+impl From<&PublicKey> for AffinePoint {
+    fn from(public_key: &PublicKey) -> AffinePoint {
+        *public_key.as_affine()
+    }
+}
+
+impl From<PublicKey> for ProjectivePoint {
+    fn from(public_key: PublicKey) -> ProjectivePoint {
+        public_key.to_projective()
+    }
+}
+
+impl From<&PublicKey> for ProjectivePoint {
+    fn from(public_key: &PublicKey) -> ProjectivePoint {
+        public_key.to_projective()
+    }
+}
+
+impl ClearCofactor<MockCurve> for ProjectivePoint {}
+
+impl<const COFACTOR: u64, const COMPRESS_POINTS: bool>
+    FromEncodedPoint<MockCurveVariant<COFACTOR, COMPRESS_POINTS>> for AffinePoint
+{
+    fn from_encoded_point(
+        point: &crate::sec1::EncodedPoint<MockCurveVariant<COFACTOR, COMPRESS_POINTS>>,
+    ) -> CtOption<Self> {
+        FromEncodedPoint::<MockCurve>::from_encoded_point(point)
+    }
+}
+
+impl<const COFACTOR: u64, const COMPRESS_POINTS: bool>
+    ToEncodedPoint<MockCurveVariant<COFACTOR, COMPRESS_POINTS>> for AffinePoint
+{
+    fn to_encoded_point(
+        &self,
+        compress: bool,
+    ) -> crate::sec1::EncodedPoint<MockCurveVariant<COFACTOR, COMPRESS_POINTS>> {
+        ToEncodedPoint::<MockCurve>::to_encoded_point(self, compress)
+    }
+}
+
 impl FromEncodedPoint<MockCurve> for ProjectivePoint {
     fn from_encoded_point(_point: &EncodedPoint) -> CtOption<Self> {
         unimplemented!();
@@ -618,7 +1181,7 @@ impl group::GroupEncoding for AffinePoint {
                 let is_identity = bytes.ct_eq(&Self::Repr::default());
                 CtOption::new(EncodedPoint::identity(), is_identity)
             })
-            .and_then(|point| Self::from_encoded_point(&point))
+            .and_then(|point| FromEncodedPoint::<MockCurve>::from_encoded_point(&point))
     }
 
     fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
@@ -626,7 +1189,7 @@ impl group::GroupEncoding for AffinePoint {
     }
 
     fn to_bytes(&self) -> Self::Repr {
-        let encoded = self.to_encoded_point(true);
+        let encoded = ToEncodedPoint::<MockCurve>::to_encoded_point(self, true);
         let mut result = CompressedPoint::<MockCurve>::default();
         result[..encoded.len()].copy_from_slice(encoded.as_bytes());
         result
@@ -655,28 +1218,62 @@ impl group::Curve for ProjectivePoint {
     fn to_affine(&self) -> AffinePoint {
         match self {
             Self::FixedBaseOutput(scalar) => AffinePoint::FixedBaseOutput(*scalar),
+            Self::Identity => AffinePoint::Identity,
+            Self::Generator => AffinePoint::Generator,
             Self::Other(affine) => *affine,
-            _ => unimplemented!(),
         }
     }
 }
 
+impl<const N: usize> BatchNormalize<[ProjectivePoint; N]> for ProjectivePoint {
+    type Output = [AffinePoint; N];
+
+    fn batch_normalize(points: &[ProjectivePoint; N]) -> [AffinePoint; N] {
+        let mut affine_points = [AffinePoint::default(); N];
+        <Self as group::Curve>::batch_normalize(points, &mut affine_points);
+        affine_points
+    }
+}
+
+impl BatchNormalize<[ProjectivePoint]> for ProjectivePoint {
+    type Output = Vec<AffinePoint>;
+
+    fn batch_normalize(points: &[ProjectivePoint]) -> Vec<AffinePoint> {
+        let mut affine_points = vec![AffinePoint::default(); points.len()];
+        <Self as group::Curve>::batch_normalize(points, &mut affine_points);
+        affine_points
+    }
+}
+
 impl LinearCombination<[(ProjectivePoint, Scalar)]> for ProjectivePoint {}
 impl<const N: usize> LinearCombination<[(ProjectivePoint, Scalar); N]> for ProjectivePoint {}
+impl LinearCombinationExt for ProjectivePoint {}
 
 impl Add<ProjectivePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn add(self, _other: ProjectivePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn add(self, other: ProjectivePoint) -> ProjectivePoint {
+        match (self, other) {
+            (Self::Identity, point) | (point, Self::Identity) => point,
+            (Self::FixedBaseOutput(scalar), Self::FixedBaseOutput(other_scalar)) => {
+                let sum = scalar + other_scalar;
+
+                if sum.is_zero().into() {
+                    Self::Identity
+                } else {
+                    Self::FixedBaseOutput(sum)
+                }
+            }
+            _ => unimplemented!(),
+        }
     }
 }
 
 impl Add<&ProjectivePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn add(self, _other: &ProjectivePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn add(self, other: &ProjectivePoint) -> ProjectivePoint {
+        self + *other
     }
 }
 
@@ -723,28 +1320,28 @@ impl SubAssign<&ProjectivePoint> for ProjectivePoint {
 impl Add<AffinePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn add(self, _other: AffinePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn add(self, other: AffinePoint) -> ProjectivePoint {
+        self + ProjectivePoint::from(other)
     }
 }
 
 impl Add<&AffinePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn add(self, _other: &AffinePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn add(self, other: &AffinePoint) -> ProjectivePoint {
+        self + *other
     }
 }
 
 impl AddAssign<AffinePoint> for ProjectivePoint {
-    fn add_assign(&mut self, _rhs: AffinePoint) {
-        unimplemented!();
+    fn add_assign(&mut self, rhs: AffinePoint) {
+        *self = *self + rhs;
     }
 }
 
 impl AddAssign<&AffinePoint> for ProjectivePoint {
-    fn add_assign(&mut self, _rhs: &AffinePoint) {
-        unimplemented!();
+    fn add_assign(&mut self, rhs: &AffinePoint) {
+        *self = *self + rhs;
     }
 }
 
@@ -829,11 +1426,338 @@ impl Neg for ProjectivePoint {
     }
 }
 
+/// Deterministic RNG for testing rejection sampling and RNG failures.
+///
+/// Outputs `zeros` zero bytes, then fails once if `fail` is set, and outputs
+/// `byte` forever afterwards. A failing request doesn't consume any of the
+/// remaining zeros, which are discarded.
+///
+/// [`RngCore::fill_bytes`] panics on failure, so infallible sampling with a
+/// failing [`ScriptedRng`] panics.
+#[derive(Clone, Debug)]
+pub struct ScriptedRng {
+    /// Number of zero bytes to output first.
+    pub zeros: usize,
+
+    /// Fail once, on the first request which isn't covered by `zeros`.
+    pub fail: bool,
+
+    /// Byte to repeat once the script is done.
+    pub byte: u8,
+}
+
+impl ScriptedRng {
+    /// Create a [`ScriptedRng`] which outputs `zeros` zero bytes followed by
+    /// `byte` forever.
+    pub const fn new(zeros: usize, byte: u8) -> Self {
+        Self {
+            zeros,
+            fail: false,
+            byte,
+        }
+    }
+
+    /// Create a [`ScriptedRng`] which outputs `zeros` zero bytes, then fails
+    /// once, then outputs `byte` forever.
+    pub const fn failing(zeros: usize, byte: u8) -> Self {
+        Self {
+            zeros,
+            fail: true,
+            byte,
+        }
+    }
+}
+
+impl RngCore for ScriptedRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).expect("RNG failure");
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> core::result::Result<(), rand_core::Error> {
+        if self.fail && self.zeros < dest.len() {
+            self.fail = false;
+            self.zeros = 0;
+            let code =
+                core::num::NonZeroU32::new(rand_core::Error::CUSTOM_START).expect("non-zero");
+            return Err(code.into());
+        }
+
+        for byte in dest {
+            if self.zeros > 0 {
+                *byte = 0;
+                self.zeros -= 1;
+            } else {
+                *byte = self.byte;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl CryptoRng for ScriptedRng {}
+
+/// Prime order group whose elements are [`Scalar`]s under addition, with the
+/// generator being one.
+///
+/// Unlike [`ProjectivePoint`] this has a complete [`group::Group`] impl, so
+/// it can be used to test generic group algorithms (e.g. multi-scalar
+/// multiplication) against a naive reference. Discrete logarithms are
+/// trivial, so it's completely insecure.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ScalarGroup(pub Scalar);
+
+impl ConditionallySelectable for ScalarGroup {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(Scalar::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl ConstantTimeEq for ScalarGroup {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl group::Group for ScalarGroup {
+    type Scalar = Scalar;
+
+    fn random(rng: impl RngCore) -> Self {
+        let mut rng = rng;
+
+        loop {
+            let scalar = Scalar::random(&mut rng);
+
+            if !bool::from(scalar.is_zero()) {
+                return Self(scalar);
+            }
+        }
+    }
+
+    fn identity() -> Self {
+        Self(Scalar::ZERO)
+    }
+
+    fn generator() -> Self {
+        Self(Scalar::ONE)
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.0.is_zero()
+    }
+
+    fn double(&self) -> Self {
+        Self(self.0.double())
+    }
+}
+
+impl group::Curve for ScalarGroup {
+    type AffineRepr = ScalarGroup;
+
+    fn to_affine(&self) -> ScalarGroup {
+        *self
+    }
+}
+
+impl MulByGenerator for ScalarGroup {
+    #[cfg(feature = "precomputed-tables")]
+    fn mul_by_generator(scalar: &Scalar) -> Self {
+        static TABLE: LazyBasepointTable<ScalarGroup, 4> = LazyBasepointTable::new();
+        TABLE.mul(scalar)
+    }
+}
+
+impl Add<ScalarGroup> for ScalarGroup {
+    type Output = ScalarGroup;
+
+    fn add(self, other: ScalarGroup) -> ScalarGroup {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Add<&ScalarGroup> for ScalarGroup {
+    type Output = ScalarGroup;
+
+    fn add(self, other: &ScalarGroup) -> ScalarGroup {
+        Self(self.0 + other.0)
+    }
+}
+
+impl AddAssign<ScalarGroup> for ScalarGroup {
+    fn add_assign(&mut self, other: ScalarGroup) {
+        self.0 += other.0;
+    }
+}
+
+impl AddAssign<&ScalarGroup> for ScalarGroup {
+    fn add_assign(&mut self, other: &ScalarGroup) {
+        self.0 += other.0;
+    }
+}
+
+impl Sub<ScalarGroup> for ScalarGroup {
+    type Output = ScalarGroup;
+
+    fn sub(self, other: ScalarGroup) -> ScalarGroup {
+        Self(self.0 - other.0)
+    }
+}
+
+impl Sub<&ScalarGroup> for ScalarGroup {
+    type Output = ScalarGroup;
+
+    fn sub(self, other: &ScalarGroup) -> ScalarGroup {
+        Self(self.0 - other.0)
+    }
+}
+
+impl SubAssign<ScalarGroup> for ScalarGroup {
+    fn sub_assign(&mut self, other: ScalarGroup) {
+        self.0 -= other.0;
+    }
+}
+
+impl SubAssign<&ScalarGroup> for ScalarGroup {
+    fn sub_assign(&mut self, other: &ScalarGroup) {
+        self.0 -= other.0;
+    }
+}
+
+impl Mul<Scalar> for ScalarGroup {
+    type Output = ScalarGroup;
+
+    fn mul(self, scalar: Scalar) -> ScalarGroup {
+        Self(self.0 * scalar)
+    }
+}
+
+impl Mul<&Scalar> for ScalarGroup {
+    type Output = ScalarGroup;
+
+    fn mul(self, scalar: &Scalar) -> ScalarGroup {
+        Self(self.0 * scalar)
+    }
+}
+
+impl MulAssign<Scalar> for ScalarGroup {
+    fn mul_assign(&mut self, scalar: Scalar) {
+        self.0 *= scalar;
+    }
+}
+
+impl MulAssign<&Scalar> for ScalarGroup {
+    fn mul_assign(&mut self, scalar: &Scalar) {
+        self.0 *= scalar;
+    }
+}
+
+impl Neg for ScalarGroup {
+    type Output = ScalarGroup;
+
+    fn neg(self) -> ScalarGroup {
+        Self(-self.0)
+    }
+}
+
+impl Neg for &ScalarGroup {
+    type Output = ScalarGroup;
+
+    fn neg(self) -> ScalarGroup {
+        -*self
+    }
+}
+
+impl Sum for ScalarGroup {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|elem| elem.0).sum())
+    }
+}
+
+impl<'a> Sum<&'a ScalarGroup> for ScalarGroup {
+    fn sum<I: Iterator<Item = &'a ScalarGroup>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Scalar;
-    use ff::PrimeField;
+    use super::{
+        AffinePoint, FieldElement, MockCurve, ProjectivePoint, Scalar, ScalarPrimitive, SecretKey,
+    };
+    use crate::{
+        bigint::{ArrayEncoding, U512},
+        decompress_y_p3mod4,
+        ops::{MulByGenerator, Reduce},
+        point::BatchNormalize,
+    };
+    use ff::{Field, PrimeField};
+    use group::{Curve, Group};
     use hex_literal::hex;
+    use rand_core::OsRng;
+
+    #[test]
+    fn public_key_into_points() {
+        let public_key = SecretKey::random(&mut OsRng).public_key();
+        assert_eq!(AffinePoint::from(public_key), *public_key.as_affine());
+        assert_eq!(AffinePoint::from(&public_key), *public_key.as_affine());
+        assert_eq!(
+            ProjectivePoint::from(public_key),
+            public_key.to_projective()
+        );
+        assert_eq!(
+            ProjectivePoint::from(&public_key),
+            public_key.to_projective()
+        );
+    }
+
+    #[test]
+    fn decompress_y_p3mod4_known_points() {
+        // (x, even y, odd y) for `G` and `3G`
+        let points = [
+            (
+                hex!("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"),
+                hex!("b01cbd1c01e58065711814b583f061e9d431cca994cea1313449bf97c840ae0a"),
+                hex!("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"),
+            ),
+            (
+                hex!("5ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c"),
+                hex!("8734640c4998ff7e374b06ce1a64a2ecd82ab036384fb83d9a79b127a27d5032"),
+                hex!("78cb9bf2b6670082c8b4f931e59b5d1327d54fcac7b047c265864ed85d82afcd"),
+            ),
+        ];
+
+        for (x, even_y, odd_y) in points {
+            let y = decompress_y_p3mod4::<MockCurve>(&x.into(), 0.into()).unwrap();
+            assert_eq!(y.to_repr().as_slice(), &even_y);
+
+            let y = decompress_y_p3mod4::<MockCurve>(&x.into(), 1.into()).unwrap();
+            assert_eq!(y.to_repr().as_slice(), &odd_y);
+        }
+    }
+
+    #[test]
+    fn decompress_y_p3mod4_rejects_invalid_x() {
+        // `1 + a + b` is not a quadratic residue
+        let mut x = [0u8; 32];
+        x[31] = 1;
+        assert!(bool::from(
+            decompress_y_p3mod4::<MockCurve>(&x.into(), 0.into()).is_none()
+        ));
+
+        // not a canonical field element
+        let x = [0xffu8; 32];
+        assert!(bool::from(
+            decompress_y_p3mod4::<MockCurve>(&x.into(), 0.into()).is_none()
+        ));
+    }
 
     #[test]
     fn round_trip() {
@@ -841,4 +1765,141 @@ mod tests {
         let scalar = Scalar::from_repr(bytes.into()).unwrap();
         assert_eq!(&bytes, scalar.to_repr().as_slice());
     }
+
+    #[test]
+    fn reduce_wide() {
+        let expected = Scalar::from_repr(
+            hex!("66e12d94f3d956202845b2392b6bec594699799c49bd6fa683244c95be79eea1").into(),
+        )
+        .unwrap();
+        assert_eq!(Scalar::reduce(U512::MAX), expected);
+        assert_eq!(
+            <Scalar as Reduce<U512>>::reduce_bytes(&[0xff; 64]),
+            expected
+        );
+    }
+
+    #[test]
+    fn scalar_primitive_conversions() {
+        for scalar in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE] {
+            let primitive = ScalarPrimitive::from(scalar);
+            assert_eq!(primitive, ScalarPrimitive::from(&scalar));
+            assert_eq!(primitive.to_bytes(), scalar.to_repr());
+            assert_eq!(Scalar::from(primitive), scalar);
+            assert_eq!(Scalar::from(&primitive), scalar);
+            assert_eq!(primitive.to_scalar(), scalar);
+        }
+    }
+
+    #[test]
+    fn batch_normalize() {
+        let points = [
+            ProjectivePoint::identity(),
+            ProjectivePoint::generator(),
+            ProjectivePoint::mul_by_generator(&Scalar::random(&mut OsRng)),
+            ProjectivePoint::Other(AffinePoint::Identity),
+            ProjectivePoint::identity(),
+        ];
+        let expected = points.map(|point| point.to_affine());
+
+        assert_eq!(
+            <ProjectivePoint as BatchNormalize<_>>::batch_normalize(&points),
+            expected
+        );
+        assert_eq!(
+            <ProjectivePoint as BatchNormalize<[_]>>::batch_normalize(&points[..]),
+            expected
+        );
+
+        let mut affine_points = [AffinePoint::Generator; 5];
+        <ProjectivePoint as Curve>::batch_normalize(&points, &mut affine_points);
+        assert_eq!(affine_points, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn batch_normalize_length_mismatch() {
+        let mut affine_points = [AffinePoint::Generator; 2];
+        <ProjectivePoint as Curve>::batch_normalize(
+            &[ProjectivePoint::identity()],
+            &mut affine_points,
+        );
+    }
+
+    #[test]
+    fn field_element() {
+        let x = FieldElement::random(&mut OsRng);
+        assert_eq!(FieldElement::from_repr(x.to_repr()).unwrap(), x);
+        assert_eq!(x * x.invert().unwrap(), FieldElement::ONE);
+        assert_eq!(x.square().sqrt().unwrap().square(), x.square());
+        assert!(bool::from(
+            FieldElement::from_repr(FieldElement::MODULUS.to_be_byte_array()).is_none()
+        ));
+    }
+
+    #[test]
+    fn from_uint() {
+        use super::MockCurve;
+        use crate::{
+            bigint::U256,
+            scalar::{FromUint, FromUintUnchecked},
+            Curve, CurveArithmetic,
+        };
+
+        // Exercise the bound on `CurveArithmetic::Scalar` from generic code
+        fn from_uint<C: CurveArithmetic>(uint: C::Uint) -> Option<C::Scalar> {
+            C::Scalar::from_uint(uint).into()
+        }
+
+        let n_minus_one = MockCurve::ORDER.wrapping_sub(&U256::ONE);
+        assert_eq!(from_uint::<MockCurve>(U256::ZERO), Some(Scalar::ZERO));
+        assert_eq!(from_uint::<MockCurve>(U256::ONE), Some(Scalar::ONE));
+        assert_eq!(from_uint::<MockCurve>(n_minus_one), Some(-Scalar::ONE));
+        assert_eq!(from_uint::<MockCurve>(MockCurve::ORDER), None);
+        assert_eq!(from_uint::<MockCurve>(U256::MAX), None);
+        assert_eq!(Scalar::from_uint_unchecked(n_minus_one), -Scalar::ONE);
+    }
+
+    #[test]
+    fn affine_coordinates() {
+        use super::{EncodedPoint, GENERATOR_X, GENERATOR_Y};
+        use crate::{point::AffineCoordinates, sec1::ToEncodedPoint};
+
+        let neg_y = (-FieldElement::from_repr(GENERATOR_Y.into()).unwrap()).to_repr();
+        let generator = AffinePoint::Generator;
+        let neg_generator = AffinePoint::Other(EncodedPoint::from_affine_coordinates(
+            &GENERATOR_X.into(),
+            &neg_y,
+            false,
+        ));
+
+        for point in [generator, neg_generator] {
+            let compressed = ToEncodedPoint::<MockCurve>::to_encoded_point(&point, true);
+            assert_eq!(
+                compressed.tag() as u8,
+                2 + u8::from(bool::from(point.y_is_odd()))
+            );
+            assert_eq!(compressed.x(), Some(&point.x()));
+
+            let decompressed = AffinePoint::Other(compressed);
+            assert_eq!(decompressed.x(), point.x());
+            assert_eq!(decompressed.y(), point.y());
+            assert_eq!(
+                decompressed.y_is_odd().unwrap_u8(),
+                point.y_is_odd().unwrap_u8()
+            );
+        }
+
+        assert_eq!(generator.y(), GENERATOR_Y);
+        assert_eq!(neg_generator.y(), neg_y);
+        assert_ne!(
+            generator.y_is_odd().unwrap_u8(),
+            neg_generator.y_is_odd().unwrap_u8()
+        );
+
+        let identity = AffinePoint::Identity;
+        assert_eq!(identity.x(), [0; 32]);
+        assert_eq!(identity.y(), [0; 32]);
+        assert!(!bool::from(identity.y_is_odd()));
+    }
 }
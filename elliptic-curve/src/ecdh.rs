@@ -108,6 +108,12 @@ where
         }
     }
 
+    /// Generate a cryptographically random [`EphemeralSecret`], returning an
+    /// error if `rng` fails.
+    pub fn try_random(rng: &mut impl CryptoRngCore) -> Result<Self, rand_core::Error> {
+        NonZeroScalar::try_random(rng).map(|scalar| Self { scalar })
+    }
+
     /// Get the public key associated with this ephemeral secret.
     ///
     /// The `compress` flag enables point compression.
@@ -18,6 +18,13 @@ impl Display for Error {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for Error {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "crypto error")
+    }
+}
+
 impl From<base16ct::Error> for Error {
     fn from(_: base16ct::Error) -> Error {
         Error
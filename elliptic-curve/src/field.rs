@@ -4,7 +4,10 @@ use crate::{
     bigint::{ArrayEncoding, ByteArray, Integer},
     Curve,
 };
-use hybrid_array::{typenum::Unsigned, Array};
+use hybrid_array::{
+    typenum::{Sum, Unsigned},
+    Array,
+};
 
 /// Size of serialized field elements of this elliptic curve.
 pub type FieldBytesSize<C> = <C as Curve>::FieldBytesSize;
@@ -12,6 +15,10 @@ pub type FieldBytesSize<C> = <C as Curve>::FieldBytesSize;
 /// Byte representation of a base/scalar field element of a given curve.
 pub type FieldBytes<C> = Array<u8, FieldBytesSize<C>>;
 
+/// Byte representation of an integer twice the size of a serialized field
+/// element, e.g. the input to a wide modular reduction.
+pub type WideBytes<C> = Array<u8, Sum<FieldBytesSize<C>, FieldBytesSize<C>>>;
+
 /// Trait for decoding/encoding `Curve::Uint` from/to [`FieldBytes`] using
 /// curve-specific rules.
 ///
@@ -60,6 +60,10 @@ pub trait JwkParameters: Curve {
 /// This type can represent either a public/private keypair, or just a
 /// public key, depending on whether or not the `d` parameter is present.
 ///
+/// The `x` and `y` coordinates are required for public keys, but may be
+/// omitted from private keys, in which case the public key is computed from
+/// `d` when needed.
+///
 /// [1]: https://tools.ietf.org/html/rfc7518#section-6
 // TODO(tarcieri): eagerly decode or validate `x`, `y`, and `d` as Base64
 #[derive(Clone)]
@@ -72,12 +76,16 @@ pub struct JwkEcKey {
     /// The x-coordinate of the elliptic curve point which is the public key
     /// value associated with this JWK as defined in RFC 7518 6.2.1.2:
     /// <https://tools.ietf.org/html/rfc7518#section-6.2.1.2>
-    x: String,
+    ///
+    /// Value is optional only if `d` is present.
+    x: Option<String>,
 
     /// The y-coordinate of the elliptic curve point which is the public key
     /// value associated with this JWK as defined in RFC 7518 6.2.1.3:
     /// <https://tools.ietf.org/html/rfc7518#section-6.2.1.3>
-    y: String,
+    ///
+    /// Value is optional only if `d` is present.
+    y: Option<String>,
 
     /// The `d` ECC private key parameter as described in RFC 7518 6.2.2.1:
     /// <https://tools.ietf.org/html/rfc7518#section-6.2.2.1>
@@ -107,6 +115,9 @@ impl JwkEcKey {
     }
 
     /// Decode a JWK into a [`PublicKey`].
+    ///
+    /// If this is a private key which omits the `x` and `y` coordinates, the
+    /// public key is computed from `d`.
     #[cfg(feature = "arithmetic")]
     pub fn to_public_key<C>(&self) -> Result<PublicKey<C>>
     where
@@ -114,7 +125,15 @@ impl JwkEcKey {
         AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
         FieldBytesSize<C>: ModulusSize,
     {
-        PublicKey::from_sec1_bytes(self.to_encoded_point::<C>()?.as_bytes())
+        match (&self.x, &self.y, &self.d) {
+            (None, None, Some(d_base64)) if self.crv == C::CRV => {
+                let mut d_bytes = decode_base64url_fe::<C>(d_base64)?;
+                let result = SecretKey::<C>::from_bytes(&d_bytes);
+                d_bytes.zeroize();
+                Ok(result?.public_key())
+            }
+            _ => PublicKey::from_sec1_bytes(self.to_encoded_point::<C>()?.as_bytes()),
+        }
     }
 
     /// Create a JWK from a SEC1 [`EncodedPoint`].
@@ -126,8 +145,8 @@ impl JwkEcKey {
         match point.coordinates() {
             Coordinates::Uncompressed { x, y } => Some(JwkEcKey {
                 crv: C::CRV.to_owned(),
-                x: Base64Url::encode_string(x),
-                y: Base64Url::encode_string(y),
+                x: Some(Base64Url::encode_string(x)),
+                y: Some(Base64Url::encode_string(y)),
                 d: None,
             }),
             _ => None,
@@ -135,6 +154,8 @@ impl JwkEcKey {
     }
 
     /// Get the public key component of this JWK as a SEC1 [`EncodedPoint`].
+    ///
+    /// Returns an error if the JWK lacks the `x` and `y` coordinates.
     pub fn to_encoded_point<C>(&self) -> Result<EncodedPoint<C>>
     where
         C: Curve + JwkParameters,
@@ -144,8 +165,8 @@ impl JwkEcKey {
             return Err(Error);
         }
 
-        let x = decode_base64url_fe::<C>(&self.x)?;
-        let y = decode_base64url_fe::<C>(&self.y)?;
+        let x = decode_base64url_fe::<C>(self.x.as_ref().ok_or(Error)?)?;
+        let y = decode_base64url_fe::<C>(self.y.as_ref().ok_or(Error)?)?;
         Ok(EncodedPoint::<C>::from_affine_coordinates(&x, &y, false))
     }
 
@@ -194,19 +215,26 @@ where
     type Error = Error;
 
     fn try_from(jwk: &JwkEcKey) -> Result<SecretKey<C>> {
-        if let Some(d_base64) = &jwk.d {
-            let pk = jwk.to_encoded_point::<C>()?;
-            let mut d_bytes = decode_base64url_fe::<C>(d_base64)?;
-            let result = SecretKey::from_slice(&d_bytes);
-            d_bytes.zeroize();
+        let d_base64 = jwk.d.as_ref().ok_or(Error)?;
+
+        // The coordinates are optional for private keys, but if present they
+        // must match the public key computed from `d`
+        let pk = match (&jwk.x, &jwk.y) {
+            (None, None) if jwk.crv == C::CRV => None,
+            _ => Some(jwk.to_encoded_point::<C>()?),
+        };
 
-            result.and_then(|secret_key| {
+        let mut d_bytes = decode_base64url_fe::<C>(d_base64)?;
+        let result = SecretKey::from_slice(&d_bytes);
+        d_bytes.zeroize();
+
+        result.and_then(|secret_key| {
+            if let Some(pk) = pk {
                 C::validate_public_key(&secret_key, &pk)?;
-                Ok(secret_key)
-            })
-        } else {
-            Err(Error)
-        }
+            }
+
+            Ok(secret_key)
+        })
     }
 }
 
@@ -231,9 +259,7 @@ where
 {
     fn from(sk: &SecretKey<C>) -> JwkEcKey {
         let mut jwk = sk.public_key().to_jwk();
-        let mut d = sk.to_bytes();
-        jwk.d = Some(Base64Url::encode_string(&d));
-        d.zeroize();
+        jwk.d = Some(Base64Url::encode_string(&sk.to_bytes_zeroizing()));
         jwk
     }
 }
@@ -262,7 +288,7 @@ where
     type Error = Error;
 
     fn try_from(jwk: &JwkEcKey) -> Result<PublicKey<C>> {
-        PublicKey::from_sec1_bytes(jwk.to_encoded_point::<C>()?.as_bytes())
+        jwk.to_public_key()
     }
 }
 
@@ -355,6 +381,9 @@ impl<'de> Deserialize<'de> for JwkEcKey {
             X,
             Y,
             D,
+            /// Members this type doesn't understand: RFC 7517 Section 4
+            /// requires them to be ignored
+            Ignore,
         }
 
         /// Field visitor
@@ -401,10 +430,7 @@ impl<'de> Deserialize<'de> for JwkEcKey {
                     b"x" => Ok(Field::X),
                     b"y" => Ok(Field::Y),
                     b"d" => Ok(Field::D),
-                    _ => Err(de::Error::unknown_field(
-                        &String::from_utf8_lossy(value),
-                        FIELDS,
-                    )),
+                    _ => Ok(Field::Ignore),
                 }
             }
         }
@@ -452,6 +478,8 @@ impl<'de> Deserialize<'de> for JwkEcKey {
                 let y = de::SeqAccess::next_element::<String>(&mut seq)?
                     .ok_or_else(|| de::Error::invalid_length(3, &DE_ERROR_MSG))?;
 
+                let (x, y) = (Some(x), Some(y));
+
                 let d = de::SeqAccess::next_element::<Option<String>>(&mut seq)?
                     .ok_or_else(|| de::Error::invalid_length(4, &DE_ERROR_MSG))?;
 
@@ -506,6 +534,9 @@ impl<'de> Deserialize<'de> for JwkEcKey {
                                 return Err(de::Error::duplicate_field(FIELDS[4]));
                             }
                         }
+                        Field::Ignore => {
+                            de::MapAccess::next_value::<de::IgnoredAny>(&mut map)?;
+                        }
                     }
                 }
 
@@ -516,8 +547,17 @@ impl<'de> Deserialize<'de> for JwkEcKey {
                 }
 
                 let crv = crv.ok_or_else(|| de::Error::missing_field("crv"))?;
-                let x = x.ok_or_else(|| de::Error::missing_field("x"))?;
-                let y = y.ok_or_else(|| de::Error::missing_field("y"))?;
+
+                // The coordinates may only be omitted from private keys
+                if d.is_none() {
+                    if x.is_none() {
+                        return Err(de::Error::missing_field("x"));
+                    }
+
+                    if y.is_none() {
+                        return Err(de::Error::missing_field("y"));
+                    }
+                }
 
                 Ok(JwkEcKey { crv, x, y, d })
             }
@@ -544,8 +584,15 @@ impl Serialize for JwkEcKey {
 
         let mut state = serializer.serialize_struct(JWK_TYPE_NAME, 5)?;
 
-        for (i, field) in [EC_KTY, &self.crv, &self.x, &self.y].iter().enumerate() {
-            state.serialize_field(FIELDS[i], field)?;
+        state.serialize_field(FIELDS[0], EC_KTY)?;
+        state.serialize_field(FIELDS[1], &self.crv)?;
+
+        if let Some(x) = &self.x {
+            state.serialize_field(FIELDS[2], x)?;
+        }
+
+        if let Some(y) = &self.y {
+            state.serialize_field(FIELDS[3], y)?;
         }
 
         if let Some(d) = &self.d {
@@ -556,6 +603,91 @@ impl Serialize for JwkEcKey {
     }
 }
 
+/// `serde` support for [`PublicKey`] as a JSON Web Key (JWK).
+///
+/// For use with `serde`'s field attributes on a [`PublicKey`] field:
+///
+/// ```text
+/// #[serde(with = "elliptic_curve::serde_jwk")]
+/// public_key: PublicKey<NistP256>,
+/// ```
+///
+/// In human-readable formats like JSON the key is a JWK object,
+/// `{"kty":"EC","crv":...,"x":...,"y":...}`, while binary formats use the
+/// compressed SEC1 encoding.
+///
+/// Deserialization checks `crv` against the curve and validates the point.
+/// JWKs containing a private key are rejected.
+///
+/// ```
+/// # #[cfg(feature = "dev")]
+/// # {
+/// use elliptic_curve::{dev::PublicKey, serde_jwk};
+///
+/// let json = r#"{"kty":"EC","crv":"P-256","x":"JRH-zL97xOSL3aoxR5p93gsqt-2Kn0YAeaqQ7zXLIbU","y":"-uo6mNXOmyVMsf0OpG7ODCTT6C8yPg76ZfgnmsFEmSY"}"#;
+/// let public_key: PublicKey =
+///     serde_jwk::deserialize(&mut serde_json::Deserializer::from_str(json)).unwrap();
+///
+/// let mut serialized = Vec::new();
+/// serde_jwk::serialize(&public_key, &mut serde_json::Serializer::new(&mut serialized)).unwrap();
+/// assert_eq!(serialized, json.as_bytes());
+/// # }
+/// ```
+#[cfg(feature = "arithmetic")]
+pub mod serde_jwk {
+    use super::{JwkEcKey, JwkParameters};
+    use crate::{
+        sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint},
+        AffinePoint, CurveArithmetic, FieldBytesSize, PublicKey,
+    };
+    use serdect::serde::{de, ser, Deserialize, Serialize};
+
+    /// Serialize a [`PublicKey`] as a JWK, or as a compressed SEC1 point in
+    /// binary formats.
+    pub fn serialize<C, S>(public_key: &PublicKey<C>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: CurveArithmetic + JwkParameters,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+        FieldBytesSize<C>: ModulusSize,
+        S: ser::Serializer,
+    {
+        if serializer.is_human_readable() {
+            public_key.to_jwk().serialize(serializer)
+        } else {
+            serdect::slice::serialize_hex_lower_or_bin(
+                &public_key.to_encoded_point(true),
+                serializer,
+            )
+        }
+    }
+
+    /// Deserialize a [`PublicKey`] from a JWK, or from a SEC1 point in
+    /// binary formats.
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<PublicKey<C>, D::Error>
+    where
+        C: CurveArithmetic + JwkParameters,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+        FieldBytesSize<C>: ModulusSize,
+        D: de::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let jwk = JwkEcKey::deserialize(deserializer)?;
+
+            if jwk.is_keypair() {
+                return Err(de::Error::custom(
+                    "expected a public JWK, found a private key",
+                ));
+            }
+
+            jwk.to_public_key::<C>()
+                .map_err(|_| de::Error::custom("invalid JWK public key"))
+        } else {
+            let bytes = serdect::slice::deserialize_hex_or_bin_vec(deserializer)?;
+            PublicKey::from_sec1_bytes(&bytes).map_err(de::Error::custom)
+        }
+    }
+}
+
 /// Decode a Base64url-encoded field element
 fn decode_base64url_fe<C: Curve>(s: &str) -> Result<FieldBytes<C>> {
     let mut result = FieldBytes::<C>::default();
@@ -569,7 +701,7 @@ mod tests {
     use super::*;
 
     #[cfg(feature = "dev")]
-    use crate::dev::MockCurve;
+    use crate::dev::{MockCurve, SecretKey};
 
     /// Example private key. From RFC 7518 Appendix C:
     /// <https://tools.ietf.org/html/rfc7518#appendix-C>
@@ -583,6 +715,15 @@ mod tests {
         }
     "#;
 
+    /// Example private key without the optional `x` and `y` coordinates.
+    const JWK_PRIVATE_KEY_WITHOUT_COORDINATES: &str = r#"
+        {
+          "kty":"EC",
+          "crv":"P-256",
+          "d":"0_NxaRPUMQoAJt50Gz8YiTr8gRTwyEaCumd-MToTmIo"
+        }
+    "#;
+
     /// Example public key.
     const JWK_PUBLIC_KEY: &str = r#"
         {
@@ -614,8 +755,14 @@ mod tests {
     fn parse_private_key() {
         let jwk = JwkEcKey::from_str(JWK_PRIVATE_KEY).unwrap();
         assert_eq!(jwk.crv, "P-256");
-        assert_eq!(jwk.x, "gI0GAILBdu7T53akrFmMyGcsF3n5dO7MmwNBHKW5SV0");
-        assert_eq!(jwk.y, "SLW_xSffzlPWrHEVI30DHM_4egVwt3NQqeUD7nMFpps");
+        assert_eq!(
+            jwk.x.as_deref(),
+            Some("gI0GAILBdu7T53akrFmMyGcsF3n5dO7MmwNBHKW5SV0")
+        );
+        assert_eq!(
+            jwk.y.as_deref(),
+            Some("SLW_xSffzlPWrHEVI30DHM_4egVwt3NQqeUD7nMFpps")
+        );
         assert_eq!(
             jwk.d.as_ref().unwrap(),
             "0_NxaRPUMQoAJt50Gz8YiTr8gRTwyEaCumd-MToTmIo"
@@ -626,8 +773,14 @@ mod tests {
     fn parse_public_key() {
         let jwk = JwkEcKey::from_str(JWK_PUBLIC_KEY).unwrap();
         assert_eq!(jwk.crv, "P-256");
-        assert_eq!(jwk.x, "gI0GAILBdu7T53akrFmMyGcsF3n5dO7MmwNBHKW5SV0");
-        assert_eq!(jwk.y, "SLW_xSffzlPWrHEVI30DHM_4egVwt3NQqeUD7nMFpps");
+        assert_eq!(
+            jwk.x.as_deref(),
+            Some("gI0GAILBdu7T53akrFmMyGcsF3n5dO7MmwNBHKW5SV0")
+        );
+        assert_eq!(
+            jwk.y.as_deref(),
+            Some("SLW_xSffzlPWrHEVI30DHM_4egVwt3NQqeUD7nMFpps")
+        );
         assert_eq!(jwk.d, None);
     }
 
@@ -660,8 +813,14 @@ mod tests {
             other => panic!("unexpected coordinates: {other:?}"),
         };
 
-        assert_eq!(&decode_base64url_fe::<MockCurve>(&jwk.x).unwrap(), x);
-        assert_eq!(&decode_base64url_fe::<MockCurve>(&jwk.y).unwrap(), y);
+        assert_eq!(
+            &decode_base64url_fe::<MockCurve>(jwk.x.as_ref().unwrap()).unwrap(),
+            x
+        );
+        assert_eq!(
+            &decode_base64url_fe::<MockCurve>(jwk.y.as_ref().unwrap()).unwrap(),
+            y
+        );
     }
 
     #[cfg(feature = "dev")]
@@ -672,4 +831,197 @@ mod tests {
         let jwk2 = JwkEcKey::from_encoded_point::<MockCurve>(&point).unwrap();
         assert_eq!(jwk, jwk2);
     }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn secret_key_jwk_round_trip() {
+        let secret_key = SecretKey::from_slice(&[0x42; 32]).unwrap();
+        let jwk = secret_key.to_jwk();
+        assert!(jwk.is_keypair());
+        assert_eq!(jwk.crv(), "P-256");
+        assert_eq!(
+            jwk.d.as_deref(),
+            Some(Base64Url::encode_string(&[0x42; 32]).as_str())
+        );
+        assert_eq!(SecretKey::from_jwk(&jwk).unwrap(), secret_key);
+
+        let jwk_str = secret_key.to_jwk_string();
+        assert!(jwk_str.contains(r#""kty":"EC""#));
+        assert_eq!(SecretKey::from_jwk_str(&jwk_str).unwrap(), secret_key);
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn secret_key_from_jwk_without_d() {
+        let jwk = JwkEcKey::from_str(JWK_PUBLIC_KEY).unwrap();
+        assert!(SecretKey::from_jwk(&jwk).is_err());
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn secret_key_from_jwk_public_key_mismatch() {
+        let mut jwk = SecretKey::from_slice(&[0x42; 32]).unwrap().to_jwk();
+        jwk.x = SecretKey::from_slice(&[0x24; 32])
+            .unwrap()
+            .to_jwk()
+            .x
+            .clone();
+        assert!(SecretKey::from_jwk(&jwk).is_err());
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn secret_key_from_jwk_zero_d() {
+        let mut jwk = SecretKey::from_slice(&[0x42; 32]).unwrap().to_jwk();
+        jwk.d = Some(Base64Url::encode_string(&[0; 32]));
+        assert!(SecretKey::from_jwk(&jwk).is_err());
+    }
+
+    #[test]
+    fn parse_private_key_without_coordinates() {
+        let jwk = JwkEcKey::from_str(JWK_PRIVATE_KEY_WITHOUT_COORDINATES).unwrap();
+        assert!(jwk.is_keypair());
+        assert_eq!(jwk.x, None);
+        assert_eq!(jwk.y, None);
+
+        let actual = jwk.to_string();
+        let expected: String = JWK_PRIVATE_KEY_WITHOUT_COORDINATES
+            .split_whitespace()
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn parse_public_key_without_coordinates() {
+        let jwk = r#"{"kty":"EC","crv":"P-256","y":"SLW_xSffzlPWrHEVI30DHM_4egVwt3NQqeUD7nMFpps"}"#;
+        assert_eq!(JwkEcKey::from_str(jwk), Err(Error));
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn secret_key_from_jwk_without_coordinates() {
+        let d = decode_base64url_fe::<MockCurve>("0_NxaRPUMQoAJt50Gz8YiTr8gRTwyEaCumd-MToTmIo");
+        let expected = SecretKey::from_bytes(&d.unwrap()).unwrap();
+        let secret_key = SecretKey::from_jwk_str(JWK_PRIVATE_KEY_WITHOUT_COORDINATES).unwrap();
+        assert_eq!(secret_key, expected);
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn public_key_from_jwk_without_coordinates() {
+        let jwk = JwkEcKey::from_str(JWK_PRIVATE_KEY_WITHOUT_COORDINATES).unwrap();
+        let secret_key = SecretKey::from_jwk(&jwk).unwrap();
+        let public_key = jwk.to_public_key::<MockCurve>().unwrap();
+        assert_eq!(public_key, secret_key.public_key());
+        assert_eq!(
+            crate::dev::PublicKey::try_from(&jwk).unwrap(),
+            secret_key.public_key()
+        );
+
+        let mut jwk = jwk;
+        jwk.crv = "P-384".to_owned();
+        assert!(jwk.to_public_key::<MockCurve>().is_err());
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn secret_key_from_jwk_with_one_coordinate() {
+        let mut jwk = SecretKey::from_slice(&[0x42; 32]).unwrap().to_jwk();
+        jwk.y = None;
+        assert!(SecretKey::from_jwk(&jwk).is_err());
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn secret_key_from_jwk_wrong_curve_without_coordinates() {
+        let mut jwk = JwkEcKey::from_str(JWK_PRIVATE_KEY_WITHOUT_COORDINATES).unwrap();
+        jwk.crv = "P-384".to_owned();
+        assert!(SecretKey::from_jwk(&jwk).is_err());
+    }
+
+    /// P-256 public key exported by WebCrypto's `exportKey("jwk", ...)`,
+    /// which includes the `key_ops` and `ext` members.
+    const WEBCRYPTO_JWK: &str = r#"{"key_ops":["verify"],"ext":true,"kty":"EC","x":"JRH-zL97xOSL3aoxR5p93gsqt-2Kn0YAeaqQ7zXLIbU","y":"-uo6mNXOmyVMsf0OpG7ODCTT6C8yPg76ZfgnmsFEmSY","crv":"P-256"}"#;
+
+    /// The same key exported by WebCrypto's `exportKey("raw", ...)`.
+    const WEBCRYPTO_RAW: [u8; 65] = hex_literal::hex!("042511feccbf7bc4e48bddaa31479a7dde0b2ab7ed8a9f460079aa90ef35cb21b5faea3a98d5ce9b254cb1fd0ea46ece0c24d3e82f323e0efa65f8279ac1449926");
+
+    #[test]
+    fn parse_ignores_unknown_members() {
+        let jwk = JwkEcKey::from_str(WEBCRYPTO_JWK).unwrap();
+        assert_eq!(jwk.crv, "P-256");
+        assert!(jwk.is_public_key());
+    }
+
+    #[cfg(feature = "dev")]
+    mod serde_jwk {
+        use super::{WEBCRYPTO_JWK, WEBCRYPTO_RAW};
+        use crate::{dev::PublicKey, sec1::ToEncodedPoint};
+        use alloc::{string::String, vec::Vec};
+
+        fn to_json(public_key: &PublicKey) -> String {
+            let mut json = Vec::new();
+            crate::serde_jwk::serialize(public_key, &mut serde_json::Serializer::new(&mut json))
+                .unwrap();
+            String::from_utf8(json).unwrap()
+        }
+
+        fn from_json(json: &str) -> serde_json::Result<PublicKey> {
+            crate::serde_jwk::deserialize(&mut serde_json::Deserializer::from_str(json))
+        }
+
+        #[test]
+        fn webcrypto_fixture() {
+            let public_key = from_json(WEBCRYPTO_JWK).unwrap();
+            assert_eq!(
+                public_key.to_encoded_point(false).as_bytes(),
+                &WEBCRYPTO_RAW[..]
+            );
+        }
+
+        #[test]
+        fn json_round_trip() {
+            let public_key = from_json(WEBCRYPTO_JWK).unwrap();
+            let json = to_json(&public_key);
+            assert_eq!(
+                json,
+                r#"{"kty":"EC","crv":"P-256","x":"JRH-zL97xOSL3aoxR5p93gsqt-2Kn0YAeaqQ7zXLIbU","y":"-uo6mNXOmyVMsf0OpG7ODCTT6C8yPg76ZfgnmsFEmSY"}"#
+            );
+            assert_eq!(from_json(&json).unwrap(), public_key);
+        }
+
+        #[test]
+        fn binary_round_trip() {
+            let public_key = from_json(WEBCRYPTO_JWK).unwrap();
+
+            let mut bytes = Vec::new();
+            crate::serde_jwk::serialize(
+                &public_key,
+                &mut bincode::Serializer::new(&mut bytes, bincode::options()),
+            )
+            .unwrap();
+
+            // Length prefix followed by the compressed SEC1 point
+            let compressed = public_key.to_encoded_point(true);
+            assert_eq!(&bytes[1..], compressed.as_bytes());
+
+            let decoded: PublicKey = crate::serde_jwk::deserialize(
+                &mut bincode::Deserializer::from_slice(&bytes, bincode::options()),
+            )
+            .unwrap();
+            assert_eq!(decoded, public_key);
+        }
+
+        #[test]
+        fn reject_wrong_curve() {
+            let json = WEBCRYPTO_JWK.replace("P-256", "P-384");
+            assert!(from_json(&json).is_err());
+        }
+
+        #[test]
+        fn reject_private_key() {
+            let json = super::JWK_PRIVATE_KEY;
+            assert!(from_json(json).is_err());
+        }
+    }
 }
@@ -0,0 +1,238 @@
+//! Key pairs, i.e. a secret key along with its corresponding public key.
+
+use crate::{CurveArithmetic, PublicKey, SecretKey};
+use rand_core::CryptoRngCore;
+use zeroize::ZeroizeOnDrop;
+
+#[cfg(feature = "pkcs8")]
+use {
+    crate::{
+        pkcs8::{self, AssociatedOid},
+        sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint},
+        AffinePoint, FieldBytesSize,
+    },
+    pkcs8::spki::{AlgorithmIdentifier, AssociatedAlgorithmIdentifier, ObjectIdentifier},
+};
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+use pkcs8::{der, EncodePrivateKey, Version};
+
+/// Elliptic curve key pair: a [`SecretKey`] along with its [`PublicKey`].
+///
+/// Computing the public key for a secret key requires a scalar
+/// multiplication, which this type performs once up front rather than on
+/// every call to [`SecretKey::public_key`].
+///
+/// The secret key is zeroized on drop.
+///
+/// # PKCS#8
+///
+/// When the `pkcs8` feature is enabled, key pairs can be decoded from PKCS#8
+/// documents, validating the public key embedded in the document (if any)
+/// against the secret key. With the `alloc` feature they're encoded as PKCS#8
+/// v2 (`OneAsymmetricKey`) documents which include the public key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyPair<C: CurveArithmetic> {
+    secret_key: SecretKey<C>,
+    public_key: PublicKey<C>,
+}
+
+impl<C> KeyPair<C>
+where
+    C: CurveArithmetic,
+{
+    /// Generate a random [`KeyPair`].
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        Self::from_secret_key(SecretKey::random(rng))
+    }
+
+    /// Create a [`KeyPair`] from a [`SecretKey`], computing its public key.
+    pub fn from_secret_key(secret_key: SecretKey<C>) -> Self {
+        let public_key = secret_key.public_key();
+
+        Self {
+            secret_key,
+            public_key,
+        }
+    }
+
+    /// Borrow the secret key.
+    pub fn secret_key(&self) -> &SecretKey<C> {
+        &self.secret_key
+    }
+
+    /// Borrow the public key.
+    pub fn public_key(&self) -> &PublicKey<C> {
+        &self.public_key
+    }
+
+    /// Convert into the secret key, discarding the public key.
+    pub fn into_secret_key(self) -> SecretKey<C> {
+        self.secret_key
+    }
+}
+
+impl<C> AsRef<SecretKey<C>> for KeyPair<C>
+where
+    C: CurveArithmetic,
+{
+    fn as_ref(&self) -> &SecretKey<C> {
+        &self.secret_key
+    }
+}
+
+impl<C> AsRef<PublicKey<C>> for KeyPair<C>
+where
+    C: CurveArithmetic,
+{
+    fn as_ref(&self) -> &PublicKey<C> {
+        &self.public_key
+    }
+}
+
+impl<C> From<SecretKey<C>> for KeyPair<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(secret_key: SecretKey<C>) -> KeyPair<C> {
+        KeyPair::from_secret_key(secret_key)
+    }
+}
+
+impl<C> From<&SecretKey<C>> for KeyPair<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(secret_key: &SecretKey<C>) -> KeyPair<C> {
+        KeyPair::from_secret_key(secret_key.clone())
+    }
+}
+
+impl<C> From<KeyPair<C>> for SecretKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(key_pair: KeyPair<C>) -> SecretKey<C> {
+        key_pair.into_secret_key()
+    }
+}
+
+impl<C> From<&KeyPair<C>> for PublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(key_pair: &KeyPair<C>) -> PublicKey<C> {
+        key_pair.public_key
+    }
+}
+
+impl<C> ZeroizeOnDrop for KeyPair<C> where C: CurveArithmetic {}
+
+#[cfg(feature = "pkcs8")]
+impl<C> AssociatedAlgorithmIdentifier for KeyPair<C>
+where
+    C: AssociatedOid + CurveArithmetic,
+{
+    type Params = ObjectIdentifier;
+
+    const ALGORITHM_IDENTIFIER: AlgorithmIdentifier<ObjectIdentifier> =
+        SecretKey::<C>::ALGORITHM_IDENTIFIER;
+}
+
+#[cfg(feature = "pkcs8")]
+impl<C> TryFrom<pkcs8::PrivateKeyInfo<'_>> for KeyPair<C>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    type Error = pkcs8::Error;
+
+    fn try_from(private_key_info: pkcs8::PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
+        SecretKey::try_from(private_key_info).map(Self::from_secret_key)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+impl<C> EncodePrivateKey for KeyPair<C>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    fn to_pkcs8_der(&self) -> pkcs8::Result<der::SecretDocument> {
+        self.secret_key
+            .to_pkcs8_der_with_public_key(&self.public_key, Version::V2)
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use crate::dev::{KeyPair, SecretKey};
+    use hex_literal::hex;
+
+    const EXAMPLE_SCALAR: [u8; 32] =
+        hex!("AABBCCDDEEFF0000000000000000000000000000000000000000000000000001");
+
+    #[test]
+    fn from_secret_key() {
+        let secret_key = SecretKey::from_slice(&EXAMPLE_SCALAR).unwrap();
+        let key_pair = KeyPair::from_secret_key(secret_key.clone());
+        assert_eq!(key_pair.secret_key(), &secret_key);
+        assert_eq!(key_pair.public_key(), &secret_key.public_key());
+        assert_eq!(SecretKey::from(key_pair), secret_key);
+    }
+
+    #[cfg(feature = "alloc")]
+    mod pkcs8 {
+        use super::EXAMPLE_SCALAR;
+        use crate::{
+            dev::{KeyPair, SecretKey},
+            pkcs8::{self, DecodePrivateKey, EncodePrivateKey, Version},
+            sec1::ToEncodedPoint,
+        };
+
+        #[test]
+        fn round_trip() {
+            let key_pair = KeyPair::from(SecretKey::from_slice(&EXAMPLE_SCALAR).unwrap());
+            let der = key_pair.to_pkcs8_der().unwrap();
+
+            let private_key_info = pkcs8::PrivateKeyInfo::try_from(der.as_bytes()).unwrap();
+            assert_eq!(private_key_info.version(), Version::V2);
+
+            assert_eq!(KeyPair::from_pkcs8_der(der.as_bytes()).unwrap(), key_pair);
+            assert_eq!(
+                SecretKey::from_pkcs8_der(der.as_bytes()).unwrap(),
+                *key_pair.secret_key()
+            );
+        }
+
+        #[test]
+        fn decode_v1() {
+            let secret_key = SecretKey::from_slice(&EXAMPLE_SCALAR).unwrap();
+            let der = secret_key.to_pkcs8_der().unwrap();
+            let key_pair = KeyPair::from_pkcs8_der(der.as_bytes()).unwrap();
+            assert_eq!(key_pair.secret_key(), &secret_key);
+        }
+
+        #[test]
+        fn reject_tampered_public_key() {
+            let key_pair = KeyPair::from(SecretKey::from_slice(&EXAMPLE_SCALAR).unwrap());
+            let other_public_key = SecretKey::from_slice(&[1; 32])
+                .unwrap()
+                .public_key()
+                .to_encoded_point(false);
+
+            let der = key_pair.to_pkcs8_der().unwrap();
+            let mut private_key_info = pkcs8::PrivateKeyInfo::try_from(der.as_bytes()).unwrap();
+            private_key_info.public_key = Some(other_public_key.as_bytes());
+
+            let tampered = pkcs8::der::SecretDocument::encode_msg(&private_key_info).unwrap();
+            assert_eq!(
+                KeyPair::from_pkcs8_der(tampered.as_bytes()),
+                Err(pkcs8::Error::KeyMalformed)
+            );
+        }
+    }
+}
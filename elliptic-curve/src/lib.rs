@@ -62,8 +62,21 @@
 //! - [`PublicKey`]
 //! - [`ScalarPrimitive`]
 //!
+//! The separate `serde-secret` feature additionally provides impls for
+//! [`SecretKey`], which serialize the secret scalar in plaintext.
+//!
 //! Please see type-specific documentation for more information.
 //!
+//! ## `defmt` support
+//!
+//! When the `defmt` feature of this crate is enabled, [`defmt::Format`] impls
+//! are provided for [`Error`], [`ScalarPrimitive`], [`SecretKey`] and
+//! (with the `sec1` feature) [`PublicKey`]. Public keys are printed as
+//! truncated hexadecimal, while [`ScalarPrimitive`] and [`SecretKey`], which
+//! may hold secrets, are always redacted.
+//!
+//! [`defmt::Format`]: https://docs.rs/defmt/latest/defmt/trait.Format.html
+//!
 //! [`RustCrypto/elliptic-curves`]: https://github.com/RustCrypto/elliptic-curves
 //! [`bp256`]: https://github.com/RustCrypto/elliptic-curves/tree/master/bp256
 //! [`bp384`]: https://github.com/RustCrypto/elliptic-curves/tree/master/bp384
@@ -104,6 +117,8 @@ mod secret_key;
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
 #[cfg(feature = "arithmetic")]
+mod key_pair;
+#[cfg(feature = "arithmetic")]
 mod public_key;
 
 #[cfg(feature = "jwk")]
@@ -114,8 +129,8 @@ mod voprf;
 
 pub use crate::{
     error::{Error, Result},
-    field::{FieldBytes, FieldBytesEncoding, FieldBytesSize},
-    scalar::ScalarPrimitive,
+    field::{FieldBytes, FieldBytesEncoding, FieldBytesSize, WideBytes},
+    scalar::{NonZeroScalarPrimitive, ScalarPrimitive},
     secret_key::SecretKey,
 };
 pub use crypto_bigint as bigint;
@@ -128,7 +143,10 @@ pub use zeroize;
 #[cfg(feature = "arithmetic")]
 pub use {
     crate::{
-        arithmetic::{CurveArithmetic, PrimeCurveArithmetic},
+        arithmetic::{
+            decompress_y_p3mod4, CurveArithmetic, PrimeCurveArithmetic, PrimeCurveParams,
+        },
+        key_pair::KeyPair,
         point::{AffinePoint, BatchNormalize, ProjectivePoint},
         public_key::PublicKey,
         scalar::{NonZeroScalar, Scalar},
@@ -140,6 +158,12 @@ pub use {
 #[cfg(feature = "jwk")]
 pub use crate::jwk::{JwkEcKey, JwkParameters};
 
+#[cfg(all(feature = "arithmetic", feature = "jwk"))]
+pub use crate::jwk::serde_jwk;
+
+#[cfg(all(feature = "arithmetic", feature = "serde"))]
+pub use crate::public_key::serde_compact;
+
 #[cfg(feature = "pkcs8")]
 pub use pkcs8;
 
@@ -180,6 +204,9 @@ pub trait Curve: 'static + Copy + Clone + Debug + Default + Eq + Ord + Send + Sy
         + bigint::AddMod<Output = Self::Uint>
         + bigint::Encoding
         + bigint::FixedInteger
+        + bigint::Gcd<Output = subtle::CtOption<Self::Uint>>
+        + bigint::InvMod
+        + bigint::MulMod<Output = Self::Uint>
         + bigint::NegMod<Output = Self::Uint>
         + bigint::Random
         + bigint::RandomMod
@@ -191,6 +218,12 @@ pub trait Curve: 'static + Copy + Clone + Debug + Default + Eq + Ord + Send + Sy
     /// Order of this elliptic curve, i.e. number of elements in the scalar
     /// field.
     const ORDER: Self::Uint;
+
+    /// Cofactor of this elliptic curve, i.e. the number of points on the curve
+    /// divided by [`Curve::ORDER`].
+    ///
+    /// Defaults to 1, i.e. a prime order curve.
+    const COFACTOR: u64 = 1;
 }
 
 /// Marker trait for elliptic curves with prime order.
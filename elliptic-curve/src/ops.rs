@@ -1,14 +1,29 @@
 //! Traits for arithmetic operations on elliptic curve field elements.
 
+#[cfg(all(feature = "alloc", feature = "bits"))]
+mod basepoint_table;
+mod lookup_table;
+
+pub use self::lookup_table::LookupTable;
 pub use core::ops::{Add, AddAssign, Mul, Neg, Shr, ShrAssign, Sub, SubAssign};
 
-use crypto_bigint::Integer;
+#[cfg(all(feature = "alloc", feature = "bits"))]
+pub use self::basepoint_table::BasepointTable;
+#[cfg(feature = "precomputed-tables")]
+pub use self::basepoint_table::LazyBasepointTable;
+
+use crypto_bigint::{BitOps, Integer};
 use group::Group;
 use subtle::{Choice, ConditionallySelectable, CtOption};
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+#[cfg(all(feature = "alloc", feature = "bits"))]
+use ff::PrimeField;
+#[cfg(feature = "bits")]
+use {crate::scalar, ff::PrimeFieldBits};
+
 /// Perform an inversion on a field element (i.e. base field element or scalar)
 pub trait Invert {
     /// Field element type
@@ -19,6 +34,9 @@ pub trait Invert {
 
     /// Invert a field element in variable time.
     ///
+    /// Curve implementations can override this with a faster variable-time
+    /// algorithm, e.g. for use when verifying signatures.
+    ///
     /// ⚠️ WARNING!
     ///
     /// This method should not be used with secret values, as its variable-time
@@ -147,6 +165,59 @@ fn invert_batch_internal<
         .is_some()
 }
 
+/// In-place batched inversion of field elements using Montgomery's trick,
+/// which costs a single inversion plus `3(n - 1)` multiplications.
+///
+/// Unlike [`BatchInvert`], zero elements don't cause the whole batch to fail:
+/// they have no inverse and are left unchanged, while all other elements are
+/// still inverted. The returned [`Choice`] is truthy if every element was
+/// inverted, and falsy if any element was zero.
+///
+/// The default implementations are built on [`Invert`] and are constant-time
+/// with respect to the values of the elements (but not their number).
+pub trait BatchInvertInPlace: ff::Field + Invert<Output = CtOption<Self>> {
+    /// Invert every element of `elems` in place.
+    #[cfg(feature = "alloc")]
+    fn batch_invert_in_place(elems: &mut [Self]) -> Choice {
+        let mut scratch = vec![Self::ZERO; elems.len()];
+        Self::batch_invert_with_scratch(elems, &mut scratch)
+    }
+
+    /// Invert every element of `elems` in place, using `scratch` to store
+    /// intermediate products instead of allocating.
+    ///
+    /// # Panics
+    ///
+    /// If `scratch` is shorter than `elems`.
+    fn batch_invert_with_scratch(elems: &mut [Self], scratch: &mut [Self]) -> Choice {
+        assert!(
+            scratch.len() >= elems.len(),
+            "scratch space must be at least as long as the elements"
+        );
+
+        // `scratch[i]` is the product of all non-zero elements before `i`
+        let mut acc = Self::ONE;
+        for (elem, product) in elems.iter().zip(scratch.iter_mut()) {
+            *product = acc;
+            acc = Self::conditional_select(&(acc * elem), &acc, elem.is_zero());
+        }
+
+        // `acc` is a product of non-zero elements, so is invertible
+        let mut inv = Invert::invert(&acc).unwrap_or(Self::ZERO);
+        let mut ret = Choice::from(1);
+
+        for (elem, product) in elems.iter_mut().zip(scratch.iter()).rev() {
+            let is_zero = elem.is_zero();
+            let elem_inv = inv * product;
+            inv = Self::conditional_select(&(inv * *elem), &inv, is_zero);
+            *elem = Self::conditional_select(&elem_inv, elem, is_zero);
+            ret &= !is_zero;
+        }
+
+        ret
+    }
+}
+
 /// Linear combination.
 ///
 /// This trait enables optimized implementations of linear combinations (e.g. Shamir's Trick).
@@ -168,9 +239,315 @@ where
     }
 }
 
+/// Linear combination of an arbitrary number of points and scalars.
+///
+/// Unlike [`LinearCombination`], this doesn't require the input to be
+/// collected into a slice or array first, which makes it usable with
+/// iterators of unknown length (e.g. in batch verification).
+///
+/// The default implementations compute each product individually and sum
+/// them. Curve implementations can override them with e.g. Strauss or
+/// Pippenger multi-scalar multiplication.
+pub trait LinearCombinationExt: Group {
+    /// Calculates `x1 * k1 + ... + xn * kn` for the given points and scalars.
+    fn lincomb_iter<I>(points_and_scalars: I) -> Self
+    where
+        I: IntoIterator<Item = (Self, Self::Scalar)>,
+    {
+        points_and_scalars
+            .into_iter()
+            .map(|(point, scalar)| point * scalar)
+            .sum()
+    }
+
+    /// Calculates `x1 * k1 + ... + xn * kn` for points and scalars given as
+    /// separate slices.
+    ///
+    /// # Panics
+    ///
+    /// If `points` and `scalars` have different lengths.
+    fn lincomb_slices(points: &[Self], scalars: &[Self::Scalar]) -> Self {
+        assert_eq!(
+            points.len(),
+            scalars.len(),
+            "points and scalars must have the same length"
+        );
+
+        Self::lincomb_iter(points.iter().copied().zip(scalars.iter().copied()))
+    }
+}
+
+/// Sum a slice of points in affine representation using mixed addition,
+/// which on most curves is cheaper than converting each point to projective
+/// representation and using projective addition.
+///
+/// Returns the identity if `points` is empty.
+pub fn sum_affine<G: group::Curve>(points: &[G::AffineRepr]) -> G {
+    points.iter().fold(G::identity(), |acc, point| acc + point)
+}
+
+/// Variable-base multi-scalar multiplication, i.e. compute
+/// `points[0] * scalars[0] + ... + points[n - 1] * scalars[n - 1]`.
+///
+/// Uses Pippenger's bucket method with a window size chosen from the number
+/// of inputs, falling back to Strauss' method (interleaved fixed-window
+/// multiplication) for small inputs where the buckets aren't worth it.
+///
+/// Returns the identity if the inputs are empty, and an error if `points`
+/// and `scalars` have different lengths.
+///
+/// <div class="warning">
+/// This function is variable-time with respect to the scalars, so it should
+/// only be used with public values, e.g. when verifying signatures.
+/// </div>
+#[cfg(all(feature = "alloc", feature = "bits"))]
+pub fn msm<G>(points: &[G], scalars: &[G::Scalar]) -> crate::Result<G>
+where
+    G: Group,
+    G::Scalar: PrimeFieldBits,
+{
+    /// Below this many inputs Strauss' method is faster than Pippenger's.
+    const PIPPENGER_THRESHOLD: usize = 32;
+
+    /// Window size used by Strauss' method.
+    const STRAUSS_WINDOW: usize = 4;
+
+    if points.len() != scalars.len() {
+        return Err(crate::Error);
+    }
+
+    let scalar_bits: Vec<_> = scalars.iter().map(PrimeFieldBits::to_le_bits).collect();
+    let num_bits = G::Scalar::NUM_BITS as usize;
+
+    if points.len() < PIPPENGER_THRESHOLD {
+        let tables: Vec<Vec<G>> = points
+            .iter()
+            .map(|point| {
+                let mut table = vec![G::identity(); 1 << STRAUSS_WINDOW];
+                for i in 1..table.len() {
+                    table[i] = table[i - 1] + point;
+                }
+                table
+            })
+            .collect();
+
+        return Ok(msm_windows(num_bits, STRAUSS_WINDOW, |start| {
+            let mut acc = G::identity();
+            for (table, bits) in tables.iter().zip(&scalar_bits) {
+                let digit = window_digit(bits, start, STRAUSS_WINDOW);
+                if digit != 0 {
+                    acc += table[digit];
+                }
+            }
+            acc
+        }));
+    }
+
+    // ln(n) + 2 is a good approximation of the optimal window size
+    let log2_len = (usize::BITS - points.len().leading_zeros()) as usize;
+    let window = log2_len * 69 / 100 + 2;
+
+    Ok(msm_windows(num_bits, window, |start| {
+        let mut buckets = vec![G::identity(); (1 << window) - 1];
+        for (point, bits) in points.iter().zip(&scalar_bits) {
+            let digit = window_digit(bits, start, window);
+            if digit != 0 {
+                buckets[digit - 1] += point;
+            }
+        }
+
+        // Compute `sum(i * buckets[i - 1])` using a running sum
+        let mut running = G::identity();
+        let mut acc = G::identity();
+        for bucket in buckets.iter().rev() {
+            running += bucket;
+            acc += running;
+        }
+        acc
+    }))
+}
+
+/// Combine the sums of each `window`-bit window of `num_bits` bit scalars,
+/// from most to least significant, by doubling in between.
+#[cfg(all(feature = "alloc", feature = "bits"))]
+fn msm_windows<G: Group>(num_bits: usize, window: usize, window_sum: impl Fn(usize) -> G) -> G {
+    let num_windows = num_bits.div_ceil(window);
+
+    (0..num_windows).rev().fold(G::identity(), |mut acc, i| {
+        for _ in 0..window {
+            acc = acc.double();
+        }
+        acc + window_sum(i * window)
+    })
+}
+
+/// Get the little endian `width`-bit digit of `bits` starting at bit `start`.
+#[cfg(all(feature = "alloc", feature = "bits"))]
+fn window_digit<R: ff::BitViewSized>(bits: &ff::FieldBits<R>, start: usize, width: usize) -> usize {
+    bits.iter()
+        .skip(start)
+        .take(width)
+        .enumerate()
+        .fold(0, |digit, (i, bit)| digit | (usize::from(*bit) << i))
+}
+
+/// Maximum number of wNAF digits supported by [`mul_wnaf_vartime`], i.e.
+/// scalars of up to 1023 bits.
+#[cfg(feature = "bits")]
+const WNAF_MAX_DIGITS: usize = 1024;
+
+/// Variable-base scalar multiplication using the width-`window` non-adjacent
+/// form (wNAF) of the scalar.
+///
+/// Precomputes the odd multiples `P, 3P, ..., (2^(window - 1) - 1)P` of the
+/// point, which are stored on the stack for `window <= 6`. Above that they're
+/// stored on the heap with the `alloc` feature, and in a larger stack buffer
+/// without it. Larger windows need fewer additions but more precomputation;
+/// `window = 4` or `5` is typically a good choice for 256-bit scalars.
+///
+/// <div class="warning">
+/// This function is variable-time with respect to the scalar, so it should
+/// only be used with public values, e.g. when verifying signatures.
+/// </div>
+///
+/// # Panics
+///
+/// If `window` is not in the range `2..=8`, or if the scalar field is larger
+/// than 1023 bits.
+#[cfg(feature = "bits")]
+pub fn mul_wnaf_vartime<G>(point: &G, scalar: &G::Scalar, window: usize) -> G
+where
+    G: Group,
+    G::Scalar: PrimeFieldBits,
+{
+    assert!((2..=8).contains(&window), "unsupported wNAF window size");
+
+    let mut digits = [0i8; WNAF_MAX_DIGITS];
+    let digits = wnaf_digits(scalar, window, &mut digits);
+
+    let mul = |table: &mut [G]| {
+        wnaf_table(point, table);
+
+        let mut acc = G::identity();
+        for &digit in digits.iter().rev() {
+            acc = acc.double();
+            wnaf_add(&mut acc, table, digit);
+        }
+        acc
+    };
+
+    let table_len = 1 << (window - 2);
+    if table_len <= 16 {
+        mul(&mut [G::identity(); 16][..table_len])
+    } else {
+        #[cfg(feature = "alloc")]
+        let mut table = vec![G::identity(); table_len];
+        #[cfg(not(feature = "alloc"))]
+        let mut table = [G::identity(); 64];
+        mul(&mut table[..table_len])
+    }
+}
+
+/// Compute `a * G + b * P` where `G` is the group's generator, interleaving
+/// the wNAF digits of both scalars (a.k.a. Shamir's trick).
+///
+/// This is the operation used to verify ECDSA-style signatures, and is
+/// considerably faster than the constant-time [`LinearCombination`].
+/// Curve implementations with faster specialized arithmetic (e.g. precomputed
+/// tables for the generator) may provide their own equivalent.
+///
+/// <div class="warning">
+/// This function is variable-time with respect to the scalars, so it should
+/// only be used with public values, e.g. when verifying signatures.
+/// </div>
+///
+/// # Panics
+///
+/// If the scalar field is larger than 1023 bits.
+#[cfg(feature = "bits")]
+pub fn mul_double_base_vartime<G>(a: &G::Scalar, point: &G, b: &G::Scalar) -> G
+where
+    G: Group,
+    G::Scalar: PrimeFieldBits,
+{
+    const WINDOW: usize = 5;
+
+    let mut a_digits = [0i8; WNAF_MAX_DIGITS];
+    let mut b_digits = [0i8; WNAF_MAX_DIGITS];
+    let a_digits = wnaf_digits(a, WINDOW, &mut a_digits);
+    let b_digits = wnaf_digits(b, WINDOW, &mut b_digits);
+
+    let mut generator_table = [G::identity(); 1 << (WINDOW - 2)];
+    let mut point_table = [G::identity(); 1 << (WINDOW - 2)];
+    wnaf_table(&G::generator(), &mut generator_table);
+    wnaf_table(point, &mut point_table);
+
+    let mut acc = G::identity();
+    for (&a_digit, &b_digit) in a_digits.iter().zip(b_digits).rev() {
+        acc = acc.double();
+        wnaf_add(&mut acc, &generator_table, a_digit);
+        wnaf_add(&mut acc, &point_table, b_digit);
+    }
+
+    acc
+}
+
+/// Write the `NUM_BITS + 1` wNAF digits of `scalar` into `digits`, starting
+/// from the least significant, and return them.
+///
+/// This uses the same recoding as [`ScalarPrimitive::to_wnaf_vartime`].
+///
+/// [`ScalarPrimitive::to_wnaf_vartime`]: crate::ScalarPrimitive::to_wnaf_vartime
+#[cfg(feature = "bits")]
+fn wnaf_digits<'a, S: PrimeFieldBits>(
+    scalar: &S,
+    window: usize,
+    digits: &'a mut [i8; WNAF_MAX_DIGITS],
+) -> &'a [i8] {
+    let num_bits = S::NUM_BITS as usize;
+    assert!(num_bits < WNAF_MAX_DIGITS, "scalar too large for wNAF");
+
+    let bits = scalar.to_le_bits();
+    let bit = |i: usize| i < num_bits && bits[i];
+    let digits = &mut digits[..=num_bits];
+
+    for (slot, digit) in digits
+        .iter_mut()
+        .zip(scalar::wnaf_vartime(bit, num_bits + 1, window))
+    {
+        *slot = digit;
+    }
+
+    digits
+}
+
+/// Fill `table` with the odd multiples of `point`, i.e. `table[i] = (2i + 1) * point`.
+#[cfg(feature = "bits")]
+fn wnaf_table<G: Group>(point: &G, table: &mut [G]) {
+    let double = point.double();
+    let mut multiple = *point;
+    for entry in table.iter_mut() {
+        *entry = multiple;
+        multiple += double;
+    }
+}
+
+/// Add the multiple of a point corresponding to a wNAF digit to `acc`.
+#[cfg(feature = "bits")]
+fn wnaf_add<G: Group>(acc: &mut G, table: &[G], digit: i8) {
+    let index = usize::from(digit.unsigned_abs() >> 1);
+    match digit.signum() {
+        1 => *acc += table[index],
+        -1 => *acc -= table[index],
+        _ => (),
+    }
+}
+
 /// Multiplication by the generator.
 ///
-/// May use optimizations (e.g. precomputed tables) when available.
+/// May use optimizations (e.g. precomputed tables) when available. See
+/// [`BasepointTable`] (and `LazyBasepointTable` with the
+/// `precomputed-tables` feature) for a generic implementation.
 // TODO(tarcieri): replace this with `Group::mul_by_generator``? (see zkcrypto/group#44)
 pub trait MulByGenerator: Group {
     /// Multiply by the generator of the prime-order subgroup.
@@ -180,6 +557,102 @@ pub trait MulByGenerator: Group {
     }
 }
 
+/// Cofactor handling for the points of a curve which may have a cofactor
+/// greater than one (see [`Curve::COFACTOR`][`crate::Curve::COFACTOR`]).
+///
+/// The provided methods are no-ops for curves with a cofactor of one, so
+/// prime order curves can implement this trait with an empty impl block.
+/// This serves the same purpose as [`group::cofactor::CofactorGroup`], which
+/// is used by [`hash2curve`][`crate::hash2curve`], but is available for any
+/// [`Group`].
+pub trait ClearCofactor<C: crate::Curve>: Group {
+    /// Multiply this point by the cofactor, mapping it into the prime order
+    /// subgroup.
+    fn clear_cofactor(&self) -> Self {
+        if C::COFACTOR == 1 {
+            *self
+        } else {
+            *self * Self::Scalar::from(C::COFACTOR)
+        }
+    }
+
+    /// Is this point in the prime order subgroup, i.e. is it free of any
+    /// small order component?
+    ///
+    /// The default implementation checks that `(n - 1) * P + P` is the
+    /// identity, where `n` is the order of the subgroup. Curves will
+    /// typically want to override it with a faster check.
+    fn is_torsion_free(&self) -> Choice {
+        is_torsion_free::<C, Self>(self)
+    }
+}
+
+/// Generic subgroup check: is `(n - 1) * P + P` the identity?
+///
+/// Always true for curves with a cofactor of 1.
+pub(crate) fn is_torsion_free<C: crate::Curve, G: Group>(point: &G) -> Choice {
+    if C::COFACTOR == 1 {
+        Choice::from(1)
+    } else {
+        (*point * -<G::Scalar as ff::Field>::ONE + point).is_identity()
+    }
+}
+
+/// Efficiently computable endomorphism of a group, i.e. a map `φ` such that
+/// `φ(P) = λ * P` for some scalar `λ`.
+///
+/// On curves like secp256k1 this is the GLV endomorphism, which lets
+/// [`mul_endo`] replace one full-width scalar multiplication with two
+/// half-width ones computed simultaneously.
+pub trait Endomorphism: Group {
+    /// Apply the endomorphism to this point.
+    fn endo(&self) -> Self;
+}
+
+/// Decompose a scalar `k` into `k1 + k2 * λ` where `λ` is the eigenvalue of
+/// the curve's [`Endomorphism`] and `k1` and `k2` are roughly half the width
+/// of `k`.
+pub trait SplitScalar: Sized {
+    /// Half-width integer type used to represent the magnitudes of `k1` and `k2`.
+    type SubScalar: Integer;
+
+    /// Split this scalar into `(|k1|, |k2|, k1_is_negative, k2_is_negative)`.
+    ///
+    /// This should be constant-time.
+    fn split(&self) -> (Self::SubScalar, Self::SubScalar, Choice, Choice);
+}
+
+/// Multiply a point by a scalar using the curve's [`Endomorphism`] and
+/// [`SplitScalar`] decomposition, computing `k1 * P + k2 * φ(P)` with a joint
+/// double-and-add over the half-width magnitudes.
+///
+/// This is constant-time provided that [`SplitScalar::split`] is. Curves
+/// without an efficient endomorphism should keep using plain scalar
+/// multiplication instead.
+pub fn mul_endo<G>(point: &G, scalar: &G::Scalar) -> G
+where
+    G: ConditionallySelectable + Endomorphism,
+    G::Scalar: SplitScalar,
+{
+    let (k1, k2, k1_is_neg, k2_is_neg) = scalar.split();
+    let endo = point.endo();
+    let p1 = G::conditional_select(point, &-*point, k1_is_neg);
+    let p2 = G::conditional_select(&endo, &-endo, k2_is_neg);
+    let p1_plus_p2 = p1 + p2;
+    let mut acc = G::identity();
+
+    for i in (0..k1.bits_precision()).rev() {
+        let (b1, b2) = (k1.bit(i), k2.bit(i));
+        let mut addend = G::identity();
+        addend.conditional_assign(&p1, b1 & !b2);
+        addend.conditional_assign(&p2, !b1 & b2);
+        addend.conditional_assign(&p1_plus_p2, b1 & b2);
+        acc = acc.double() + addend;
+    }
+
+    acc
+}
+
 /// Modular reduction.
 pub trait Reduce<Uint: Integer>: Sized {
     /// Bytes used as input to [`Reduce::reduce_bytes`].
@@ -207,3 +680,485 @@ pub trait ReduceNonZero<Uint: Integer>: Reduce<Uint> + Sized {
     /// to a non-zero output.
     fn reduce_nonzero_bytes(bytes: &Self::Bytes) -> Self;
 }
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{
+        mul_endo, sum_affine, BatchInvertInPlace, ClearCofactor, Endomorphism, Invert,
+        LinearCombinationExt, SplitScalar,
+    };
+    use crate::{
+        bigint::{U128, U256},
+        dev::{MockCurve, MockCurveVariant, Scalar, ScalarGroup},
+    };
+    use alloc::vec::Vec;
+    use core::{
+        iter::Sum,
+        ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    };
+    use ff::{Field, PrimeField};
+    use group::Group;
+    use rand_core::{OsRng, RngCore};
+    use subtle::Choice;
+
+    #[cfg(feature = "bits")]
+    use super::{msm, mul_double_base_vartime, mul_wnaf_vartime};
+
+    /// Mock endomorphism with eigenvalue `λ = -2^128`, so scalars split into
+    /// their low and high 128-bit halves with `k2` always negated.
+    fn lambda() -> Scalar {
+        let two_64 = Scalar::from(u64::MAX) + Scalar::ONE;
+        -(two_64 * two_64)
+    }
+
+    impl Endomorphism for ScalarGroup {
+        fn endo(&self) -> Self {
+            *self * lambda()
+        }
+    }
+
+    impl SplitScalar for Scalar {
+        type SubScalar = U128;
+
+        fn split(&self) -> (U128, U128, Choice, Choice) {
+            let (lo, hi) = U256::from_be_slice(&self.to_repr()).split();
+            (lo, hi, Choice::from(0), Choice::from(1))
+        }
+    }
+
+    /// `MockCurve` with a cofactor of 4.
+    type Cofactor4Curve = MockCurveVariant<4, false>;
+
+    impl ClearCofactor<MockCurve> for ScalarGroup {}
+    impl ClearCofactor<Cofactor4Curve> for ScalarGroup {}
+
+    /// `ScalarGroup × Z/4`, i.e. a group of order `4n` whose points may have a
+    /// small order component.
+    ///
+    /// Scalars act on the `Z/4` component through their canonical integer
+    /// representative, so `n * P = (0, b)` for `P = (a, b)` as `n ≡ 1 mod 4`.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    struct SmallOrderGroup(ScalarGroup, u8);
+
+    impl SmallOrderGroup {
+        fn small_order(&self) -> u8 {
+            self.1 % 4
+        }
+    }
+
+    impl Group for SmallOrderGroup {
+        type Scalar = Scalar;
+
+        fn random(mut rng: impl RngCore) -> Self {
+            let b = (rng.next_u32() % 4) as u8;
+            Self(ScalarGroup::random(rng), b)
+        }
+
+        fn identity() -> Self {
+            Self(ScalarGroup::identity(), 0)
+        }
+
+        fn generator() -> Self {
+            Self(ScalarGroup::generator(), 1)
+        }
+
+        fn is_identity(&self) -> Choice {
+            self.0.is_identity() & Choice::from(u8::from(self.small_order() == 0))
+        }
+
+        fn double(&self) -> Self {
+            *self + self
+        }
+    }
+
+    impl Add<&SmallOrderGroup> for SmallOrderGroup {
+        type Output = Self;
+
+        fn add(self, other: &Self) -> Self {
+            Self(self.0 + other.0, (self.1 + other.1) % 4)
+        }
+    }
+
+    impl Add for SmallOrderGroup {
+        type Output = Self;
+
+        fn add(self, other: Self) -> Self {
+            Add::add(self, &other)
+        }
+    }
+
+    impl AddAssign<&SmallOrderGroup> for SmallOrderGroup {
+        fn add_assign(&mut self, other: &Self) {
+            *self = *self + other;
+        }
+    }
+
+    impl AddAssign for SmallOrderGroup {
+        fn add_assign(&mut self, other: Self) {
+            *self = *self + other;
+        }
+    }
+
+    impl Sub<&SmallOrderGroup> for SmallOrderGroup {
+        type Output = Self;
+
+        fn sub(self, other: &Self) -> Self {
+            self + -*other
+        }
+    }
+
+    impl Sub for SmallOrderGroup {
+        type Output = Self;
+
+        fn sub(self, other: Self) -> Self {
+            Sub::sub(self, &other)
+        }
+    }
+
+    impl SubAssign<&SmallOrderGroup> for SmallOrderGroup {
+        fn sub_assign(&mut self, other: &Self) {
+            *self = *self - other;
+        }
+    }
+
+    impl SubAssign for SmallOrderGroup {
+        fn sub_assign(&mut self, other: Self) {
+            *self = *self - other;
+        }
+    }
+
+    impl Mul<&Scalar> for SmallOrderGroup {
+        type Output = Self;
+
+        fn mul(self, scalar: &Scalar) -> Self {
+            let k = scalar.to_repr()[31] % 4;
+            Self(self.0 * scalar, (self.1 * k) % 4)
+        }
+    }
+
+    impl Mul<Scalar> for SmallOrderGroup {
+        type Output = Self;
+
+        fn mul(self, scalar: Scalar) -> Self {
+            self * &scalar
+        }
+    }
+
+    impl MulAssign<&Scalar> for SmallOrderGroup {
+        fn mul_assign(&mut self, scalar: &Scalar) {
+            *self = *self * scalar;
+        }
+    }
+
+    impl MulAssign<Scalar> for SmallOrderGroup {
+        fn mul_assign(&mut self, scalar: Scalar) {
+            *self = *self * scalar;
+        }
+    }
+
+    impl Neg for SmallOrderGroup {
+        type Output = Self;
+
+        fn neg(self) -> Self {
+            Self(-self.0, (4 - self.small_order()) % 4)
+        }
+    }
+
+    impl Sum for SmallOrderGroup {
+        fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+            iter.fold(Self::identity(), |acc, point| acc + point)
+        }
+    }
+
+    impl<'a> Sum<&'a SmallOrderGroup> for SmallOrderGroup {
+        fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+            iter.copied().sum()
+        }
+    }
+
+    impl ClearCofactor<Cofactor4Curve> for SmallOrderGroup {}
+
+    #[test]
+    fn clear_cofactor_small_order() {
+        let point = ScalarGroup::random(&mut OsRng);
+        let four = Scalar::from(4u64);
+
+        for b in 0..4 {
+            let p = SmallOrderGroup(point, b);
+            let cleared = ClearCofactor::<Cofactor4Curve>::clear_cofactor(&p);
+
+            assert_eq!(cleared, SmallOrderGroup(point * four, 0));
+            assert!(bool::from(
+                ClearCofactor::<Cofactor4Curve>::is_torsion_free(&cleared)
+            ));
+            assert_eq!(
+                bool::from(ClearCofactor::<Cofactor4Curve>::is_torsion_free(&p)),
+                b == 0
+            );
+        }
+    }
+
+    #[test]
+    fn default_torsion_check_rejects_small_order() {
+        let point = ScalarGroup::random(&mut OsRng);
+
+        // Always true for a cofactor of 1, without computing anything
+        for b in 0..4 {
+            assert!(bool::from(super::is_torsion_free::<MockCurve, _>(
+                &SmallOrderGroup(point, b)
+            )));
+        }
+
+        assert!(bool::from(super::is_torsion_free::<Cofactor4Curve, _>(
+            &SmallOrderGroup(point, 0)
+        )));
+        assert!(bool::from(super::is_torsion_free::<Cofactor4Curve, _>(
+            &SmallOrderGroup::identity()
+        )));
+
+        for b in 1..4 {
+            assert!(!bool::from(super::is_torsion_free::<Cofactor4Curve, _>(
+                &SmallOrderGroup(point, b)
+            )));
+            assert!(!bool::from(super::is_torsion_free::<Cofactor4Curve, _>(
+                &SmallOrderGroup(ScalarGroup::identity(), b)
+            )));
+        }
+    }
+
+    #[test]
+    fn clear_cofactor() {
+        let point = ScalarGroup::random(&mut OsRng);
+
+        assert_eq!(ClearCofactor::<MockCurve>::clear_cofactor(&point), point);
+        assert!(bool::from(ClearCofactor::<MockCurve>::is_torsion_free(
+            &point
+        )));
+
+        assert_eq!(
+            ClearCofactor::<Cofactor4Curve>::clear_cofactor(&point),
+            point * Scalar::from(4u64)
+        );
+        assert!(bool::from(
+            ClearCofactor::<Cofactor4Curve>::is_torsion_free(&point)
+        ));
+        assert!(bool::from(
+            ClearCofactor::<Cofactor4Curve>::is_torsion_free(&ScalarGroup::identity())
+        ));
+    }
+
+    impl LinearCombinationExt for ScalarGroup {}
+
+    #[test]
+    fn lincomb_matches_naive() {
+        for len in [0, 1, 2, 3, 16] {
+            let mut points: Vec<_> = (0..len).map(|_| ScalarGroup::random(&mut OsRng)).collect();
+            let mut scalars: Vec<_> = (0..len).map(|_| Scalar::random(&mut OsRng)).collect();
+
+            // Include some edge cases
+            if len > 2 {
+                points[0] = ScalarGroup::identity();
+                scalars[1] = Scalar::ZERO;
+                scalars[2] = -Scalar::ONE;
+            }
+
+            let expected = points
+                .iter()
+                .zip(&scalars)
+                .fold(ScalarGroup::identity(), |acc, (p, s)| acc + *p * s);
+
+            assert_eq!(
+                ScalarGroup::lincomb_iter(points.iter().copied().zip(scalars.iter().copied())),
+                expected,
+                "len = {len}"
+            );
+            assert_eq!(
+                ScalarGroup::lincomb_slices(&points, &scalars),
+                expected,
+                "len = {len}"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn lincomb_slices_length_mismatch() {
+        ScalarGroup::lincomb_slices(&[ScalarGroup::generator()], &[]);
+    }
+
+    #[test]
+    fn sum_affine_matches_sum() {
+        assert_eq!(sum_affine::<ScalarGroup>(&[]), ScalarGroup::identity());
+
+        let mut points: Vec<_> = (0..64).map(|_| ScalarGroup::random(&mut OsRng)).collect();
+        points[3] = ScalarGroup::identity();
+        points[7] = points[6];
+        points[8] = -points[9];
+
+        let expected = points
+            .iter()
+            .fold(ScalarGroup::identity(), |acc, p| acc + p);
+        assert_eq!(sum_affine::<ScalarGroup>(&points), expected);
+    }
+
+    #[cfg(feature = "bits")]
+    #[test]
+    fn mul_wnaf_vartime_matches_mul() {
+        let scalars = [Scalar::ZERO, Scalar::ONE, -Scalar::ONE]
+            .into_iter()
+            .chain((0..8).map(|_| Scalar::random(&mut OsRng)));
+
+        for scalar in scalars {
+            let point = ScalarGroup::random(&mut OsRng);
+            let expected = point * scalar;
+
+            for window in 2..=8 {
+                assert_eq!(
+                    mul_wnaf_vartime(&point, &scalar, window),
+                    expected,
+                    "window = {window}"
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "bits")]
+    #[test]
+    fn mul_double_base_vartime_matches_lincomb() {
+        let scalars = [Scalar::ZERO, Scalar::ONE, -Scalar::ONE]
+            .into_iter()
+            .chain((0..8).map(|_| Scalar::random(&mut OsRng)));
+
+        for a in scalars.clone() {
+            for b in scalars.clone() {
+                let point = ScalarGroup::random(&mut OsRng);
+                assert_eq!(
+                    mul_double_base_vartime(&a, &point, &b),
+                    ScalarGroup::generator() * a + point * b
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "bits")]
+    #[test]
+    #[should_panic]
+    fn mul_wnaf_vartime_rejects_window() {
+        mul_wnaf_vartime(&ScalarGroup::generator(), &Scalar::ONE, 9);
+    }
+
+    #[test]
+    fn mul_endo_matches_mul() {
+        let scalars = [Scalar::ZERO, Scalar::ONE, -Scalar::ONE, lambda()]
+            .into_iter()
+            .chain((0..16).map(|_| Scalar::random(&mut OsRng)));
+
+        for scalar in scalars {
+            let point = ScalarGroup::random(&mut OsRng);
+            assert_eq!(mul_endo(&point, &scalar), point * scalar);
+        }
+    }
+
+    fn check_batch_invert(elems: &[Scalar]) {
+        let mut inverted = elems.to_vec();
+        let all_nonzero = Scalar::batch_invert_in_place(&mut inverted);
+        assert_eq!(
+            bool::from(all_nonzero),
+            elems.iter().all(|elem| !bool::from(elem.is_zero()))
+        );
+
+        for (elem, inv) in elems.iter().zip(&inverted) {
+            assert_eq!(*inv, Field::invert(elem).unwrap_or(Scalar::ZERO));
+        }
+
+        let mut scratch = vec![Scalar::ONE; elems.len() + 1];
+        let mut inverted_with_scratch = elems.to_vec();
+        assert_eq!(
+            bool::from(Scalar::batch_invert_with_scratch(
+                &mut inverted_with_scratch,
+                &mut scratch
+            )),
+            bool::from(all_nonzero)
+        );
+        assert_eq!(inverted, inverted_with_scratch);
+    }
+
+    #[test]
+    fn invert_vartime_default() {
+        for scalar in [Scalar::ZERO, Scalar::ONE, Scalar::random(&mut OsRng)] {
+            assert_eq!(
+                Option::from(scalar.invert_vartime()),
+                Option::<Scalar>::from(Invert::invert(&scalar))
+            );
+        }
+    }
+
+    #[test]
+    fn batch_invert_in_place() {
+        check_batch_invert(&[]);
+        check_batch_invert(&[Scalar::ZERO]);
+        check_batch_invert(&[Scalar::ONE]);
+
+        for len in 1..32 {
+            let mut elems: Vec<_> = (0..len).map(|_| Scalar::random(&mut OsRng)).collect();
+            check_batch_invert(&elems);
+
+            // Zero out a random selection of elements
+            for elem in elems.iter_mut() {
+                if OsRng.next_u32() % 3 == 0 {
+                    *elem = Scalar::ZERO;
+                }
+            }
+            check_batch_invert(&elems);
+
+            elems.fill(Scalar::ZERO);
+            check_batch_invert(&elems);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn batch_invert_with_short_scratch() {
+        let mut elems = [Scalar::ONE; 2];
+        Scalar::batch_invert_with_scratch(&mut elems, &mut [Scalar::ZERO]);
+    }
+
+    #[cfg(feature = "bits")]
+    fn random_inputs(len: usize) -> (Vec<ScalarGroup>, Vec<Scalar>) {
+        let mut points: Vec<_> = (0..len).map(|_| ScalarGroup::random(&mut OsRng)).collect();
+        let mut scalars: Vec<_> = (0..len).map(|_| Scalar::random(&mut OsRng)).collect();
+
+        // Include some edge cases
+        if len > 2 {
+            points[0] = ScalarGroup::identity();
+            scalars[1] = Scalar::ZERO;
+            scalars[2] = -Scalar::ONE;
+        }
+
+        (points, scalars)
+    }
+
+    #[cfg(feature = "bits")]
+    #[test]
+    fn msm_matches_naive() {
+        for len in [1, 2, 3, 31, 32, 33, 1000] {
+            let (points, scalars) = random_inputs(len);
+            let expected: ScalarGroup = points.iter().zip(&scalars).map(|(p, s)| *p * s).sum();
+            assert_eq!(msm(&points, &scalars), Ok(expected), "len = {len}");
+        }
+    }
+
+    #[cfg(feature = "bits")]
+    #[test]
+    fn msm_empty() {
+        assert_eq!(msm::<ScalarGroup>(&[], &[]), Ok(ScalarGroup::identity()));
+    }
+
+    #[cfg(feature = "bits")]
+    #[test]
+    fn msm_length_mismatch() {
+        let (points, scalars) = random_inputs(3);
+        assert!(msm(&points, &scalars[..2]).is_err());
+        assert!(msm(&points[..2], &scalars).is_err());
+    }
+}
@@ -0,0 +1,194 @@
+//! Precomputed tables for fixed-base scalar multiplication.
+
+use alloc::vec::Vec;
+use ff::{PrimeField, PrimeFieldBits};
+use group::Group;
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+#[cfg(feature = "precomputed-tables")]
+use {alloc::boxed::Box, once_cell::race::OnceBox};
+
+/// Precomputed multiples of a fixed base point (typically the generator),
+/// for constant-time fixed-base scalar multiplication.
+///
+/// The scalar is split into `W`-bit windows, and for each window the table
+/// stores all `2^W` multiples of the base point shifted into that window.
+/// Multiplication then needs no doublings and one addition per window, with
+/// each table entry selected in constant time.
+///
+/// # Memory usage
+///
+/// The table holds `ceil(NUM_BITS / W) * 2^W` group elements, where
+/// `NUM_BITS` is the bit size of the scalar field. For a 256-bit scalar
+/// field that's 1024 elements for `W = 4`, 2048 for `W = 5`, 4096 for
+/// `W = 6` and 8192 for `W = 8`. Larger windows need fewer additions but
+/// more memory and more work per constant-time lookup, so `W = 4` to
+/// `W = 6` is typically a good choice.
+#[derive(Clone, Debug)]
+pub struct BasepointTable<G, const W: usize> {
+    /// `2^W` consecutive multiples of `2^(W * i) * base` for each window `i`.
+    tables: Vec<G>,
+}
+
+impl<G, const W: usize> BasepointTable<G, W>
+where
+    G: ConditionallySelectable + Group,
+    G::Scalar: PrimeFieldBits,
+{
+    /// Precompute a table for the given base point.
+    ///
+    /// # Panics
+    ///
+    /// If `W` is not in the range `1..=8`.
+    pub fn new(base: G) -> Self {
+        assert!((1..=8).contains(&W), "window size must be between 1 and 8");
+
+        let num_windows = (G::Scalar::NUM_BITS as usize).div_ceil(W);
+        let mut tables = Vec::with_capacity(num_windows << W);
+        let mut window_base = base;
+
+        for _ in 0..num_windows {
+            let mut multiple = G::identity();
+
+            for _ in 0..(1 << W) {
+                tables.push(multiple);
+                multiple += window_base;
+            }
+
+            // `multiple` is now `2^W * window_base`
+            window_base = multiple;
+        }
+
+        Self { tables }
+    }
+
+    /// Multiply the base point by the given scalar in constant time.
+    pub fn mul(&self, scalar: &G::Scalar) -> G {
+        let bits = scalar.to_le_bits();
+        let mut bits = bits.iter().take(G::Scalar::NUM_BITS as usize);
+        let mut acc = G::identity();
+
+        for table in self.tables.chunks_exact(1 << W) {
+            let digit = bits
+                .by_ref()
+                .take(W)
+                .enumerate()
+                .fold(0u16, |digit, (i, bit)| digit | (u16::from(*bit) << i));
+
+            let mut entry = G::identity();
+            for (j, candidate) in (0u16..).zip(table) {
+                entry.conditional_assign(candidate, j.ct_eq(&digit));
+            }
+
+            acc += entry;
+        }
+
+        acc
+    }
+}
+
+/// [`BasepointTable`] for a group's generator which is computed on first
+/// use, intended to be stored in a `static`.
+///
+/// This works on `no_std` targets with `alloc`. If several threads race to
+/// initialize the table, each of them may compute it, but only one result is
+/// kept and all callers use the same table.
+///
+/// Curve implementations can use this to implement
+/// [`MulByGenerator`][`super::MulByGenerator`] with a table stored in a
+/// `static`, as `dev::ScalarGroup` does:
+///
+/// ```
+/// # #[cfg(feature = "dev")]
+/// # {
+/// use elliptic_curve::{
+///     dev::{Scalar, ScalarGroup},
+///     ops::{LazyBasepointTable, MulByGenerator},
+///     Group,
+/// };
+///
+/// // Body of `MulByGenerator::mul_by_generator` for `ScalarGroup`
+/// fn mul_by_generator(scalar: &Scalar) -> ScalarGroup {
+///     static TABLE: LazyBasepointTable<ScalarGroup, 4> = LazyBasepointTable::new();
+///     TABLE.mul(scalar)
+/// }
+///
+/// let scalar = Scalar::from(42u64);
+/// assert_eq!(mul_by_generator(&scalar), ScalarGroup::generator() * scalar);
+/// assert_eq!(ScalarGroup::mul_by_generator(&scalar), mul_by_generator(&scalar));
+/// # }
+/// ```
+#[cfg(feature = "precomputed-tables")]
+#[derive(Debug, Default)]
+pub struct LazyBasepointTable<G, const W: usize> {
+    table: OnceBox<BasepointTable<G, W>>,
+}
+
+#[cfg(feature = "precomputed-tables")]
+impl<G, const W: usize> LazyBasepointTable<G, W>
+where
+    G: ConditionallySelectable + Group,
+    G::Scalar: PrimeFieldBits,
+{
+    /// Create a new table which hasn't been computed yet.
+    pub const fn new() -> Self {
+        Self {
+            table: OnceBox::new(),
+        }
+    }
+
+    /// Get the table, computing it if this is the first call.
+    pub fn get(&self) -> &BasepointTable<G, W> {
+        self.table
+            .get_or_init(|| Box::new(BasepointTable::new(G::generator())))
+    }
+
+    /// Multiply the generator by the given scalar in constant time.
+    pub fn mul(&self, scalar: &G::Scalar) -> G {
+        self.get().mul(scalar)
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::BasepointTable;
+    use crate::dev::{Scalar, ScalarGroup};
+    use ff::Field;
+    use group::Group;
+    use rand_core::OsRng;
+
+    fn check<const W: usize>() {
+        let base = ScalarGroup::random(&mut OsRng);
+        let table = BasepointTable::<_, W>::new(base);
+
+        let scalars = [Scalar::ZERO, Scalar::ONE, -Scalar::ONE]
+            .into_iter()
+            .chain((0..16).map(|_| Scalar::random(&mut OsRng)));
+
+        for scalar in scalars {
+            assert_eq!(table.mul(&scalar), base * scalar, "W = {W}");
+        }
+    }
+
+    #[test]
+    fn mul() {
+        check::<1>();
+        check::<3>();
+        check::<4>();
+        check::<5>();
+        check::<8>();
+    }
+
+    #[cfg(feature = "precomputed-tables")]
+    #[test]
+    fn lazy() {
+        use super::LazyBasepointTable;
+
+        static TABLE: LazyBasepointTable<ScalarGroup, 4> = LazyBasepointTable::new();
+
+        for _ in 0..4 {
+            let scalar = Scalar::random(&mut OsRng);
+            assert_eq!(TABLE.mul(&scalar), ScalarGroup::generator() * scalar);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Constant-time lookup tables.
+
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+
+/// Table of precomputed values (e.g. multiples of a point for windowed
+/// scalar multiplication) which can be indexed in constant time.
+///
+/// Every lookup scans the whole table, so the time taken doesn't depend on
+/// the index.
+#[derive(Clone, Copy, Debug)]
+pub struct LookupTable<T, const N: usize> {
+    entries: [T; N],
+}
+
+impl<T, const N: usize> LookupTable<T, N>
+where
+    T: ConditionallySelectable,
+{
+    /// Entries are indexed by a `u8`, so the table must have between 1 and
+    /// 256 entries. Evaluating this fails to compile for other sizes.
+    const SIZE_CHECK: () = assert!(
+        N > 0 && N <= 256,
+        "lookup table must have between 1 and 256 entries"
+    );
+
+    /// Create a new lookup table from the given entries.
+    ///
+    /// Fails to compile if `N` is zero or greater than 256, since entries are
+    /// indexed by a `u8`.
+    pub fn new(entries: [T; N]) -> Self {
+        let () = Self::SIZE_CHECK;
+        Self { entries }
+    }
+
+    /// Get the entry at `index` in constant time.
+    ///
+    /// If `index` is out of range, the entry at index 0 is returned.
+    pub fn select(&self, index: u8) -> T {
+        let mut ret = self.entries[0];
+
+        for (i, entry) in (0u8..=u8::MAX).zip(&self.entries).skip(1) {
+            ret.conditional_assign(entry, i.ct_eq(&index));
+        }
+
+        ret
+    }
+
+    /// Get the entry at `|digit|` in constant time, negated if `digit` is
+    /// negative.
+    ///
+    /// If `|digit|` is out of range, the entry at index 0 is used.
+    pub fn select_signed(&self, digit: i8) -> T
+    where
+        T: ConditionallyNegatable,
+    {
+        let mut ret = self.select(digit.unsigned_abs());
+        ret.conditional_negate(Choice::from(u8::from(digit < 0)));
+        ret
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for LookupTable<T, N> {
+    fn as_ref(&self) -> &[T] {
+        &self.entries
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for LookupTable<T, N>
+where
+    T: ConditionallySelectable,
+{
+    fn from(entries: [T; N]) -> Self {
+        Self::new(entries)
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::LookupTable;
+    use crate::dev::{Scalar, ScalarGroup};
+    use ff::Field;
+    use group::Group;
+    use rand_core::OsRng;
+
+    #[test]
+    fn select_scalars() {
+        let entries: [Scalar; 16] = core::array::from_fn(|_| Scalar::random(&mut OsRng));
+        let table = LookupTable::new(entries);
+
+        for (i, entry) in (0u8..).zip(&entries) {
+            assert_eq!(table.select(i), *entry);
+        }
+
+        assert_eq!(table.select(16), entries[0]);
+        assert_eq!(table.select(u8::MAX), entries[0]);
+    }
+
+    #[test]
+    fn select_full_table() {
+        let mut entries = [0u8; 256];
+        for (entry, i) in entries.iter_mut().zip(0u8..=u8::MAX) {
+            *entry = i;
+        }
+        let table = LookupTable::new(entries);
+
+        for index in 0..=u8::MAX {
+            assert_eq!(table.select(index), index);
+        }
+    }
+
+    #[test]
+    fn select_points_signed() {
+        let point = ScalarGroup::random(&mut OsRng);
+        let table =
+            LookupTable::from([0u64, 1, 2, 3, 4, 5, 6, 7, 8].map(|i| point * Scalar::from(i)));
+
+        for digit in -8i8..=8 {
+            let expected = point * Scalar::from(u64::from(digit.unsigned_abs()));
+            let expected = if digit < 0 { -expected } else { expected };
+            assert_eq!(table.select_signed(digit), expected, "digit = {digit}");
+        }
+
+        assert_eq!(table.select_signed(i8::MIN), ScalarGroup::identity());
+        assert_eq!(table.select_signed(9), ScalarGroup::identity());
+    }
+}
@@ -20,6 +20,11 @@ pub type AffinePoint<C> = <C as CurveArithmetic>::AffinePoint;
 pub type ProjectivePoint<C> = <C as CurveArithmetic>::ProjectivePoint;
 
 /// Access to the affine coordinates of an elliptic curve point.
+///
+/// The identity point has no affine representation, so for it all
+/// coordinates are serialized as zero and [`AffineCoordinates::y_is_odd`]
+/// is falsy. Callers which need to distinguish it from a point with zero
+/// coordinates should check for the identity separately.
 // TODO: use zkcrypto/group#30 coordinate API when available
 pub trait AffineCoordinates {
     /// Field element representation.
@@ -28,11 +33,21 @@ pub trait AffineCoordinates {
     /// Get the affine x-coordinate as a serialized field element.
     fn x(&self) -> Self::FieldRepr;
 
+    /// Get the affine y-coordinate as a serialized field element.
+    fn y(&self) -> Self::FieldRepr;
+
     /// Is the affine y-coordinate odd?
     fn y_is_odd(&self) -> Choice;
 }
 
 /// Normalize point(s) in projective representation by converting them to their affine ones.
+///
+/// This is generic around `Points` so curve implementations can provide both
+/// `[ProjectivePoint; N]` impls, which need no allocation, and `[ProjectivePoint]`
+/// impls which return a `Vec`. Callers which already have an output buffer can
+/// use [`group::Curve::batch_normalize`] instead, which panics if the lengths
+/// of the input and output slices differ. Curves which override it with a
+/// shared inversion can implement this trait in terms of it.
 #[cfg(feature = "arithmetic")]
 pub trait BatchNormalize<Points: ?Sized>: group::Curve {
     /// The output of the batch normalization; a container of affine points.
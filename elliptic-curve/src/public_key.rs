@@ -1,10 +1,14 @@
 //! Elliptic curve public keys.
 
 use crate::{
-    point::NonIdentity, AffinePoint, CurveArithmetic, Error, NonZeroScalar, ProjectivePoint, Result,
+    ops::MulByGenerator,
+    point::{AffineCoordinates, DecompressPoint, NonIdentity},
+    AffinePoint, CurveArithmetic, Error, FieldBytes, NonZeroScalar, ProjectivePoint, Result,
+    Scalar,
 };
 use core::fmt::Debug;
 use group::{Curve, Group};
+use subtle::{Choice, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "jwk")]
 use crate::{JwkEcKey, JwkParameters};
@@ -18,26 +22,53 @@ use core::str::FromStr;
 #[cfg(feature = "sec1")]
 use {
     crate::{
-        point::PointCompression,
-        sec1::{CompressedPoint, EncodedPoint, FromEncodedPoint, ModulusSize, ToEncodedPoint},
-        FieldBytesSize,
+        point::{DecompactPoint, PointCompression},
+        sec1::{
+            CompressedPoint, Coordinates, EncodedPoint, FromEncodedPoint, HybridPoint, ModulusSize,
+            ToCompactEncodedPoint, ToEncodedPoint, HYBRID_EVEN_Y_TAG, HYBRID_ODD_Y_TAG,
+        },
+        FieldBytesSize, PrimeCurveParams,
+    },
+    base16ct::HexDisplay,
+    core::{
+        cmp::Ordering,
+        fmt,
+        hash::{Hash, Hasher},
     },
-    core::cmp::Ordering,
-    subtle::{Choice, CtOption},
+    ff::PrimeField,
 };
 
 #[cfg(all(feature = "alloc", feature = "pkcs8"))]
 use pkcs8::EncodePublicKey;
 
 #[cfg(all(feature = "alloc", feature = "sec1"))]
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
+
+#[cfg(feature = "jwk")]
+use alloc::string::ToString;
 
-#[cfg(any(feature = "jwk", feature = "pem"))]
-use alloc::string::{String, ToString};
+#[cfg(any(
+    all(feature = "alloc", feature = "fingerprint"),
+    feature = "jwk",
+    feature = "pem"
+))]
+use alloc::string::String;
 
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
 
+#[cfg(all(feature = "digest", feature = "pkcs8"))]
+use digest::{Digest, Output};
+
+#[cfg(feature = "fingerprint")]
+use sha2::Sha256;
+
+#[cfg(all(feature = "alloc", feature = "fingerprint"))]
+use base64ct::{Base64, Encoding};
+
+#[cfg(feature = "pem")]
+use pem_rfc7468 as pem;
+
 #[cfg(any(feature = "pem", feature = "serde"))]
 use pkcs8::DecodePublicKey;
 
@@ -45,9 +76,10 @@ use pkcs8::DecodePublicKey;
 use {
     crate::{
         pkcs8::{self, AssociatedOid},
+        sec1::{algorithm_identifier_der_len, der_tlv_len, uncompressed_point_bit_string_der_len},
         ALGORITHM_OID,
     },
-    pkcs8::der,
+    pkcs8::der::{self, Encode},
 };
 
 /// Elliptic curve public keys.
@@ -55,6 +87,62 @@ use {
 /// This is a wrapper type for [`AffinePoint`] which ensures an inner
 /// non-identity point and provides a common place to handle encoding/decoding.
 ///
+/// For curves with a cofactor greater than one, the point is also checked to
+/// be in the prime order subgroup using [`CurveArithmetic::is_torsion_free`].
+/// This check is a no-op for prime order curves.
+///
+/// # Equality
+///
+/// [`PublicKey`] implements [`ConstantTimeEq`] using the curve's
+/// [`ConstantTimeEq`] impl for [`AffinePoint`], and its [`PartialEq`] impl
+/// is defined in terms of it, so comparing keys doesn't leak where they
+/// differ through timing, assuming the curve's impl is constant time.
+///
+/// # Arithmetic
+///
+/// A [`PublicKey`] can be used in point arithmetic via
+/// [`PublicKey::as_affine`] (or its [`AsRef`] impl) and
+/// [`PublicKey::to_projective`], neither of which validates the point again.
+///
+/// Coherence rules prevent this crate from providing generic [`From`] impls
+/// for a curve's [`AffinePoint`] and [`ProjectivePoint`] types, so curve
+/// crates are expected to provide them, as the mock curve does:
+///
+/// ```
+/// # #[cfg(feature = "dev")]
+/// # {
+/// use elliptic_curve::{
+///     dev::{ProjectivePoint, Scalar, SecretKey},
+///     ops::MulByGenerator,
+/// };
+///
+/// let public_key = SecretKey::from_slice(&[0x42; 32]).unwrap().public_key();
+/// let tweak = Scalar::from(7u8);
+///
+/// let q: ProjectivePoint = (&public_key).into();
+/// let r = q + ProjectivePoint::mul_by_generator(&tweak);
+/// assert_eq!(r, public_key.derive_child(&tweak).unwrap().to_projective());
+/// # }
+/// ```
+///
+/// # Formatting
+///
+/// When the `sec1` feature is enabled, [`PublicKey`] implements
+/// [`fmt::Display`] (and [`fmt::LowerHex`]/[`fmt::UpperHex`]) as the hex
+/// encoding of its compressed SEC1 point, which the [`FromStr`] impl parses:
+///
+/// ```
+/// # #[cfg(all(feature = "dev", feature = "pem"))]
+/// # {
+/// use elliptic_curve::{dev::AffinePoint, dev::PublicKey};
+///
+/// let public_key = PublicKey::from_affine(AffinePoint::Generator).unwrap();
+/// let hex = public_key.to_string();
+/// assert_eq!(hex, "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296");
+/// assert_eq!(hex.parse::<PublicKey>().unwrap().to_string(), hex);
+/// # }
+/// ```
+///
 /// # Parsing "SPKI" Keys
 ///
 /// X.509 `SubjectPublicKeyInfo` (SPKI) is a commonly used format for encoding
@@ -75,18 +163,71 @@ use {
 /// trait to parse it.
 ///
 /// When the `pem` feature of this crate (or a specific RustCrypto elliptic
-/// curve crate) is enabled, a [`FromStr`] impl is also available.
+/// curve crate) is enabled, [`PublicKey::from_public_key_pem`] and
+/// [`PublicKey::to_public_key_pem`] are available without importing those
+/// traits, along with a [`FromStr`] impl which accepts either SPKI PEM or a
+/// hex-encoded SEC1 point:
+///
+/// ```
+/// # #[cfg(all(feature = "dev", feature = "pem"))]
+/// # {
+/// use elliptic_curve::{dev::MockCurve, PublicKey};
+///
+/// let public_key = "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296\
+///                   4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"
+///     .parse::<PublicKey<MockCurve>>()
+///     .unwrap();
+///
+/// let pem = public_key.to_public_key_pem(Default::default()).unwrap();
+/// assert_eq!(pem.parse::<PublicKey<MockCurve>>().unwrap(), public_key);
+/// # }
+/// ```
 ///
 /// # `serde` support
 ///
 /// When the optional `serde` feature of this create is enabled, [`Serialize`]
 /// and [`Deserialize`] impls are provided for this type.
 ///
-/// The serialization is binary-oriented and supports ASN.1 DER
-/// Subject Public Key Info (SPKI) as the encoding format.
+/// The serialization is the SEC1 `Elliptic-Curve-Point-to-Octet-String`
+/// encoding of the point, compressed or not according to the curve's
+/// [`PointCompression`] impl, and hex encoded for textual formats.
+/// Deserialization accepts both compressed and uncompressed points, as well
+/// as compact points for curves whose [`FromEncodedPoint`] impl supports
+/// them (see [`DecompactPoint`]). Compact points are never produced, since
+/// only keys with the canonical y-coordinate can be compacted: use
+/// [`ToCompactEncodedPoint`] or [`serde_compact`] to opt into them.
+///
+/// Versions before 0.14 serialized public keys as SPKI DER documents instead:
+/// these are still accepted when deserializing.
 ///
 /// For a more text-friendly encoding of public keys, use [`JwkEcKey`] instead.
-#[derive(Clone, Debug, Eq, PartialEq)]
+///
+/// # Ordering and hashing
+///
+/// When the `sec1` feature is enabled, [`Ord`] and [`Hash`] impls are
+/// provided which are defined over the compressed SEC1 encoding of the
+/// public key. The ordering is therefore a lexicographic ordering of those
+/// bytes, which is stable across platforms and releases but has no algebraic
+/// meaning.
+///
+/// ```
+/// # #[cfg(feature = "dev")]
+/// # {
+/// use elliptic_curve::dev::{PublicKey, SecretKey};
+/// use std::collections::BTreeMap;
+///
+/// let alice = SecretKey::from_slice(&[1; 32]).unwrap().public_key();
+/// let bob = SecretKey::from_slice(&[2; 32]).unwrap().public_key();
+///
+/// let mut participants = BTreeMap::<PublicKey, &str>::new();
+/// participants.insert(bob, "bob");
+/// participants.insert(alice, "alice");
+///
+/// let names: Vec<&str> = participants.values().copied().collect();
+/// assert_eq!(names, ["alice", "bob"]);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
 pub struct PublicKey<C>
 where
     C: CurveArithmetic,
@@ -99,7 +240,30 @@ where
     C: CurveArithmetic,
 {
     /// Convert an [`AffinePoint`] into a [`PublicKey`]
+    ///
+    /// Returns an error if the point is the identity or, for curves with a
+    /// cofactor, isn't in the prime order subgroup.
     pub fn from_affine(point: AffinePoint<C>) -> Result<Self> {
+        if Self::is_valid(&point).into() {
+            Ok(Self { point })
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Convert an [`AffinePoint`] into a [`PublicKey`] without checking that
+    /// it's in the prime order subgroup.
+    ///
+    /// This is intended for points which are already known to be in the
+    /// subgroup, e.g. because they were validated previously, and avoids
+    /// the cost of the check for curves with a cofactor. The point is still
+    /// checked not to be the identity.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// Using a point outside the prime order subgroup as a public key can
+    /// enable small subgroup attacks, e.g. against ECDH.
+    pub fn from_affine_unchecked(point: AffinePoint<C>) -> Result<Self> {
         if ProjectivePoint::<C>::from(point).is_identity().into() {
             Err(Error)
         } else {
@@ -107,6 +271,13 @@ where
         }
     }
 
+    /// Is the given point a valid public key, i.e. a non-identity point in
+    /// the prime order subgroup?
+    fn is_valid(point: &AffinePoint<C>) -> Choice {
+        let point = ProjectivePoint::<C>::from(*point);
+        !point.is_identity() & C::is_torsion_free(&point)
+    }
+
     /// Compute a [`PublicKey`] from a secret [`NonZeroScalar`] value
     /// (i.e. a secret key represented as a raw scalar value)
     pub fn from_secret_scalar(scalar: &NonZeroScalar<C>) -> Self {
@@ -116,11 +287,66 @@ where
         }
     }
 
+    /// Derive a child public key by adding `tweak * G` to this key, i.e.
+    /// `Q' = Q + tweak * G`.
+    ///
+    /// This is the public counterpart of
+    /// [`SecretKey::derive_child`][`crate::SecretKey::derive_child`], such
+    /// that the public key of a child secret key is the child of its public
+    /// key.
+    ///
+    /// Returns an error if the result is the identity.
+    pub fn derive_child(&self, tweak: &Scalar<C>) -> Result<Self> {
+        let point = self.to_projective() + C::ProjectivePoint::mul_by_generator(tweak);
+        Self::from_affine(point.to_affine())
+    }
+
+    /// Add another public key to this one, i.e. compute `Q = P₁ + P₂`.
+    ///
+    /// This is useful for protocols like key blinding or aggregation. The
+    /// public key of the sum of two secret keys is the sum of their public
+    /// keys.
+    ///
+    /// Returns an error if the result is the identity, i.e. if `other` is
+    /// the negation of this key.
+    pub fn add_point(&self, other: &Self) -> Result<Self> {
+        Self::combine(&[*self, *other])
+    }
+
+    /// Compute the sum of the given public keys.
+    ///
+    /// The keys are accumulated in projective form with mixed additions, so
+    /// only the final sum is converted back to affine coordinates.
+    ///
+    /// Returns an error if `keys` is empty or the result is the identity.
+    pub fn combine(keys: &[Self]) -> Result<Self> {
+        let sum = keys
+            .iter()
+            .fold(ProjectivePoint::<C>::identity(), |sum, key| {
+                sum + key.as_affine()
+            });
+
+        // Sums of points in the prime order subgroup stay in the subgroup
+        Self::from_affine_unchecked(sum.to_affine())
+    }
+
     /// Decode [`PublicKey`] (compressed or uncompressed) from the
     /// `Elliptic-Curve-Point-to-Octet-String` encoding described in
     /// SEC 1: Elliptic Curve Cryptography (Version 2.0) section
     /// 2.3.3 (page 10).
     ///
+    /// Compressed points are decoded by the curve's [`FromEncodedPoint`]
+    /// impl, which curves can implement in terms of [`DecompressPoint`].
+    /// An x-coordinate which isn't on the curve results in an error.
+    ///
+    /// The identity encoding (a single `0x00` byte) is also an error; see
+    /// [`PublicKey::from_encoded_point_allowing_identity`] for protocols which
+    /// need to accept it.
+    ///
+    /// ANSI X9.62 hybrid points (tags `0x06` and `0x07`) are also accepted,
+    /// and are treated as uncompressed points once the parity implied by the
+    /// tag has been checked against the y-coordinate (see [`HybridPoint`]).
+    ///
     /// <http://www.secg.org/sec1-v2.pdf>
     #[cfg(feature = "sec1")]
     pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self>
@@ -128,10 +354,97 @@ where
         FieldBytesSize<C>: ModulusSize,
         AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
     {
-        let point = EncodedPoint::<C>::from_bytes(bytes).map_err(|_| Error)?;
+        let point = match bytes.first() {
+            Some(&HYBRID_EVEN_Y_TAG | &HYBRID_ODD_Y_TAG) => {
+                EncodedPoint::<C>::from_hybrid_bytes(bytes)?
+            }
+            _ => EncodedPoint::<C>::from_bytes(bytes).map_err(|_| Error)?,
+        };
         Option::from(Self::from_encoded_point(&point)).ok_or(Error)
     }
 
+    /// Initialize [`PublicKey`] from an [`EncodedPoint`], additionally
+    /// checking that uncompressed coordinates satisfy the curve equation.
+    ///
+    /// This is useful for curves whose [`FromEncodedPoint`] impl for
+    /// [`AffinePoint`] doesn't validate coordinates itself. Compressed and
+    /// compact points are always on the curve if they can be decompressed.
+    ///
+    /// Returns an error if the point is the identity or is not on the curve.
+    #[cfg(feature = "sec1")]
+    pub fn from_encoded_point_checked(encoded_point: &EncodedPoint<C>) -> Result<Self>
+    where
+        C: PrimeCurveParams,
+        FieldBytesSize<C>: ModulusSize,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    {
+        if let Coordinates::Uncompressed { x, y } = encoded_point.coordinates() {
+            let x = Option::from(C::FieldElement::from_repr(x.clone())).ok_or(Error)?;
+            let y = Option::from(C::FieldElement::from_repr(y.clone())).ok_or(Error)?;
+
+            if !bool::from(C::is_on_curve(&x, &y)) {
+                return Err(Error);
+            }
+        }
+
+        Option::from(Self::from_encoded_point(encoded_point)).ok_or(Error)
+    }
+
+    /// Initialize [`PublicKey`] from an [`EncodedPoint`] which may be the
+    /// identity, for protocols which need to represent it.
+    ///
+    /// Returns `Ok(None)` for the identity encoding (a single `0x00` byte),
+    /// and otherwise behaves like the [`FromEncodedPoint`] impl, returning an
+    /// error if the point is invalid.
+    ///
+    /// [`PublicKey`] itself never holds the identity: the other constructors
+    /// reject it.
+    #[cfg(feature = "sec1")]
+    pub fn from_encoded_point_allowing_identity(
+        encoded_point: &EncodedPoint<C>,
+    ) -> Result<Option<Self>>
+    where
+        FieldBytesSize<C>: ModulusSize,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    {
+        if encoded_point.is_identity() {
+            return Ok(None);
+        }
+
+        Option::from(Self::from_encoded_point(encoded_point))
+            .map(Some)
+            .ok_or(Error)
+    }
+
+    /// Decode a batch of [`EncodedPoint`]s into [`PublicKey`]s, preserving
+    /// their order.
+    ///
+    /// Each point is validated as with the [`FromEncodedPoint`] impl. The
+    /// conversion is all-or-nothing: if any point is invalid, no keys are
+    /// returned and the error is the index of the first invalid point.
+    ///
+    /// SEC1 points decode directly to affine coordinates, so unlike
+    /// converting projective points there's no field inversion to share
+    /// across the batch; use [`BatchNormalize`] for the latter.
+    ///
+    /// [`BatchNormalize`]: crate::point::BatchNormalize
+    #[cfg(all(feature = "alloc", feature = "sec1"))]
+    pub fn batch_from_encoded_points(
+        points: &[EncodedPoint<C>],
+    ) -> core::result::Result<Vec<Self>, usize>
+    where
+        FieldBytesSize<C>: ModulusSize,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    {
+        let mut keys = Vec::with_capacity(points.len());
+
+        for (i, point) in points.iter().enumerate() {
+            keys.push(Option::from(Self::from_encoded_point(point)).ok_or(i)?);
+        }
+
+        Ok(keys)
+    }
+
     /// Convert this [`PublicKey`] into the
     /// `Elliptic-Curve-Point-to-Octet-String` encoding described in
     /// SEC 1: Elliptic Curve Cryptography (Version 2.0) section 2.3.3
@@ -148,6 +461,53 @@ where
         EncodedPoint::<C>::from(self).to_bytes()
     }
 
+    /// Write the SEC1 `Elliptic-Curve-Point-to-Octet-String` encoding of
+    /// this [`PublicKey`] into `out`, returning the written prefix of `out`.
+    ///
+    /// This is the same encoding as [`ToEncodedPoint::to_encoded_point`]
+    /// with the given point compression, but doesn't require `alloc`.
+    ///
+    /// Returns an error if `out` is too short for the encoding.
+    #[cfg(feature = "sec1")]
+    pub fn write_sec1<'a>(&self, out: &'a mut [u8], compress: bool) -> Result<&'a [u8]>
+    where
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+        FieldBytesSize<C>: ModulusSize,
+    {
+        let point = self.to_encoded_point(compress);
+        let out = out.get_mut(..point.len()).ok_or(Error)?;
+        out.copy_from_slice(point.as_bytes());
+        Ok(out)
+    }
+
+    /// Decode a [`PublicKey`] from an x-only encoding, where the
+    /// y-coordinate is the even one of its two possible values (as in
+    /// BIP340).
+    ///
+    /// Returns `None` if `x` isn't the x-coordinate of a point on the curve.
+    pub fn from_x_only(x: &FieldBytes<C>) -> CtOption<Self>
+    where
+        AffinePoint<C>: DecompressPoint<C>,
+    {
+        AffinePoint::<C>::decompress(x, Choice::from(0))
+            .and_then(|point| CtOption::new(Self { point }, Self::is_valid(&point)))
+    }
+
+    /// Serialize the x-coordinate of this [`PublicKey`], discarding the
+    /// parity of its y-coordinate.
+    ///
+    /// This is the inverse of [`PublicKey::from_x_only`] only if
+    /// [`PublicKey::has_even_y`] is true: otherwise it decodes to this
+    /// key's negation.
+    pub fn to_x_only_bytes(&self) -> FieldBytes<C> {
+        self.point.x()
+    }
+
+    /// Is the y-coordinate of this [`PublicKey`] even?
+    pub fn has_even_y(&self) -> Choice {
+        !self.point.y_is_odd()
+    }
+
     /// Borrow the inner [`AffinePoint`] from this [`PublicKey`].
     ///
     /// In ECC, public keys are elliptic curve points.
@@ -221,6 +581,26 @@ where
 
 impl<C> Copy for PublicKey<C> where C: CurveArithmetic {}
 
+impl<C> ConstantTimeEq for PublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.point.ct_eq(&other.point)
+    }
+}
+
+impl<C> Eq for PublicKey<C> where C: CurveArithmetic {}
+
+impl<C> PartialEq for PublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
 #[cfg(feature = "sec1")]
 impl<C> FromEncodedPoint<C> for PublicKey<C>
 where
@@ -230,11 +610,52 @@ where
 {
     /// Initialize [`PublicKey`] from an [`EncodedPoint`]
     fn from_encoded_point(encoded_point: &EncodedPoint<C>) -> CtOption<Self> {
-        AffinePoint::<C>::from_encoded_point(encoded_point).and_then(|point| {
-            // Defeating the point of `subtle`, but the use case is specifically a public key
-            let is_identity = Choice::from(u8::from(encoded_point.is_identity()));
-            CtOption::new(PublicKey { point }, !is_identity)
-        })
+        AffinePoint::<C>::from_encoded_point(encoded_point)
+            .and_then(|point| CtOption::new(PublicKey { point }, Self::is_valid(&point)))
+    }
+}
+
+#[cfg(feature = "sec1")]
+impl<C> DecompactPoint<C> for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: DecompactPoint<C>,
+{
+    /// Decompact a [`PublicKey`] from its x-coordinate, selecting the
+    /// curve's canonical y-coordinate.
+    fn decompact(x: &FieldBytes<C>) -> CtOption<Self> {
+        AffinePoint::<C>::decompact(x)
+            .and_then(|point| CtOption::new(PublicKey { point }, Self::is_valid(&point)))
+    }
+}
+
+#[cfg(feature = "sec1")]
+impl<C> ToCompactEncodedPoint<C> for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: ToCompactEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    /// Serialize this [`PublicKey`] as a compact SEC1 [`EncodedPoint`].
+    ///
+    /// Returns `None` if the key's y-coordinate isn't the canonical one
+    /// chosen by decompaction, in which case the key can't be compacted.
+    fn to_compact_encoded_point(&self) -> CtOption<EncodedPoint<C>> {
+        self.point.to_compact_encoded_point()
+    }
+}
+
+#[cfg(feature = "sec1")]
+impl<C> DecompressPoint<C> for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: DecompressPoint<C>,
+{
+    /// Decompress a [`PublicKey`] from its x-coordinate, selecting the
+    /// y-coordinate with the given parity.
+    fn decompress(x: &FieldBytes<C>, y_is_odd: Choice) -> CtOption<Self> {
+        AffinePoint::<C>::decompress(x, y_is_odd)
+            .and_then(|point| CtOption::new(PublicKey { point }, Self::is_valid(&point)))
     }
 }
 
@@ -310,6 +731,8 @@ where
     }
 }
 
+/// This conversion doesn't check that the point is in the prime order
+/// subgroup, as with [`PublicKey::from_affine_unchecked`].
 impl<C, P> From<&NonIdentity<P>> for PublicKey<C>
 where
     C: CurveArithmetic,
@@ -340,6 +763,23 @@ where
     }
 }
 
+/// Prints the first 9 bytes of the compressed SEC1 encoding, i.e. the tag
+/// byte and the first 8 bytes of the x-coordinate, as hexadecimal.
+#[cfg(all(feature = "defmt", feature = "sec1"))]
+impl<C> defmt::Format for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let point = self.to_encoded_point(true);
+        let bytes = point.as_bytes();
+        let len = bytes.len().min(9);
+        defmt::write!(f, "PublicKey({=[u8]:02x}..)", &bytes[..len])
+    }
+}
+
 #[cfg(feature = "sec1")]
 impl<C> PartialOrd for PublicKey<C>
 where
@@ -362,8 +802,75 @@ where
     fn cmp(&self, other: &Self) -> Ordering {
         // TODO(tarcieri): more efficient implementation?
         // This is implemented this way to reduce bounds for `AffinePoint<C>`
-        self.to_encoded_point(false)
-            .cmp(&other.to_encoded_point(false))
+        self.to_encoded_point(true)
+            .cmp(&other.to_encoded_point(true))
+    }
+}
+
+#[cfg(feature = "sec1")]
+impl<C> Hash for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_encoded_point(true).as_bytes().hash(state);
+    }
+}
+
+/// Formats the compressed SEC1 encoding of the public key as lower case hex.
+///
+/// The output always has the same length for a given curve.
+#[cfg(feature = "sec1")]
+impl<C> fmt::Display for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "sec1")]
+impl<C> fmt::LowerHex for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        write!(
+            f,
+            "{:x}",
+            HexDisplay(self.to_encoded_point(true).as_bytes())
+        )
+    }
+}
+
+#[cfg(feature = "sec1")]
+impl<C> fmt::UpperHex for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        write!(
+            f,
+            "{:X}",
+            HexDisplay(self.to_encoded_point(true).as_bytes())
+        )
     }
 }
 
@@ -460,7 +967,7 @@ where
     type Error = pkcs8::spki::Error;
 
     fn try_from(spki: &pkcs8::SubjectPublicKeyInfoRef<'_>) -> pkcs8::spki::Result<Self> {
-        spki.algorithm.assert_oids(ALGORITHM_OID, C::OID)?;
+        crate::sec1::validate_algorithm_identifier::<C>(&spki.algorithm)?;
 
         let public_key_bytes = spki
             .subject_public_key
@@ -472,6 +979,100 @@ where
     }
 }
 
+#[cfg(all(feature = "sec1", feature = "pkcs8"))]
+impl<C> PublicKey<C>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    /// Maximum length of the ASN.1 DER `SubjectPublicKeyInfo` encoding of a
+    /// public key for this curve, i.e. the buffer size needed by
+    /// [`PublicKey::write_public_key_der`].
+    pub const MAX_PUBLIC_KEY_DER_LEN: usize = der_tlv_len(
+        algorithm_identifier_der_len::<C>() + uncompressed_point_bit_string_der_len::<C>(),
+    );
+
+    /// Serialize this public key as an ASN.1 DER `SubjectPublicKeyInfo`
+    /// into the provided buffer, without requiring `alloc`.
+    ///
+    /// Returns the prefix of `buf` containing the encoded document, which is
+    /// identical to the output of `EncodePublicKey::to_public_key_der`, or
+    /// an error if `buf` is shorter than the encoding.
+    pub fn write_public_key_der<'a>(&self, buf: &'a mut [u8]) -> pkcs8::spki::Result<&'a [u8]> {
+        let public_key_bytes = self.to_encoded_point(false);
+        let subject_public_key = der::asn1::BitStringRef::new(0, public_key_bytes.as_bytes())?;
+
+        let spki = pkcs8::SubjectPublicKeyInfo {
+            algorithm: Self::ALGORITHM_IDENTIFIER,
+            subject_public_key,
+        };
+
+        Ok(spki.encode_to_slice(buf)?)
+    }
+}
+
+#[cfg(all(feature = "digest", feature = "pkcs8"))]
+impl<C> PublicKey<C>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    /// Compute the fingerprint of this public key using the digest `D`, i.e.
+    /// the hash of its ASN.1 DER `SubjectPublicKeyInfo` encoding as produced
+    /// by `EncodePublicKey::to_public_key_der`.
+    ///
+    /// The encoding is hashed as it's produced, so this doesn't require
+    /// `alloc`.
+    pub fn fingerprint<D: Digest>(&self) -> Output<D> {
+        let public_key_bytes = self.to_encoded_point(false);
+        let mut writer = DigestWriter(D::new());
+
+        der::asn1::BitStringRef::new(0, public_key_bytes.as_bytes())
+            .and_then(|subject_public_key| {
+                pkcs8::SubjectPublicKeyInfo {
+                    algorithm: Self::ALGORITHM_IDENTIFIER,
+                    subject_public_key,
+                }
+                .encode(&mut writer)
+            })
+            .expect("SPKI encoding error");
+
+        writer.0.finalize()
+    }
+
+    /// Compute the SHA-256 fingerprint of this public key, as used for e.g.
+    /// certificate and key pinning.
+    ///
+    /// See [`PublicKey::fingerprint`] for more information.
+    #[cfg(feature = "fingerprint")]
+    pub fn fingerprint_sha256(&self) -> [u8; 32] {
+        self.fingerprint::<Sha256>().into()
+    }
+
+    /// Compute the SHA-256 fingerprint of this public key encoded as
+    /// (padded) Base64, as used by [RFC 7469] HTTP Public Key Pinning.
+    ///
+    /// [RFC 7469]: https://datatracker.ietf.org/doc/html/rfc7469#section-2.4
+    #[cfg(all(feature = "alloc", feature = "fingerprint"))]
+    pub fn fingerprint_sha256_base64(&self) -> String {
+        Base64::encode_string(&self.fingerprint_sha256())
+    }
+}
+
+/// [`der::Writer`] which hashes its output rather than storing it.
+#[cfg(all(feature = "digest", feature = "pkcs8"))]
+struct DigestWriter<D>(D);
+
+#[cfg(all(feature = "digest", feature = "pkcs8"))]
+impl<D: Digest> der::Writer for DigestWriter<D> {
+    fn write(&mut self, slice: &[u8]) -> der::Result<()> {
+        self.0.update(slice);
+        Ok(())
+    }
+}
+
 #[cfg(all(feature = "alloc", feature = "pkcs8"))]
 impl<C> EncodePublicKey for PublicKey<C>
 where
@@ -492,36 +1093,64 @@ where
 }
 
 #[cfg(feature = "pem")]
-impl<C> FromStr for PublicKey<C>
+impl<C> PublicKey<C>
 where
     C: AssociatedOid + CurveArithmetic,
     AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
     FieldBytesSize<C>: ModulusSize,
 {
-    type Err = Error;
+    /// Parse a PEM-encoded SPKI public key, i.e. one beginning with
+    /// `-----BEGIN PUBLIC KEY-----`.
+    ///
+    /// This is the same as [`DecodePublicKey::from_public_key_pem`], but
+    /// doesn't require importing the trait.
+    pub fn from_public_key_pem(s: &str) -> Result<Self> {
+        <Self as DecodePublicKey>::from_public_key_pem(s).map_err(|_| Error)
+    }
 
-    fn from_str(s: &str) -> Result<Self> {
-        Self::from_public_key_pem(s).map_err(|_| Error)
+    /// Serialize this public key as PEM-encoded SPKI with the given
+    /// [`pem::LineEnding`].
+    ///
+    /// This is the same as [`EncodePublicKey::to_public_key_pem`], but
+    /// doesn't require importing the trait.
+    pub fn to_public_key_pem(&self, line_ending: pem::LineEnding) -> Result<String> {
+        <Self as EncodePublicKey>::to_public_key_pem(self, line_ending).map_err(|_| Error)
     }
 }
 
+/// Parse a [`PublicKey`] from either SPKI PEM or a hex-encoded SEC1 point.
+///
+/// Input beginning with a PEM header (`-----BEGIN `) is parsed as SPKI PEM
+/// and anything else as hex, in either case and optionally prefixed with
+/// `0x`, which makes this the inverse of the [`fmt::Display`],
+/// [`fmt::LowerHex`] and [`fmt::UpperHex`] impls.
+///
+/// There's no fallback from one format to the other: malformed PEM is an
+/// error even if it happens to be valid hex, and surrounding whitespace
+/// isn't accepted in hex input.
 #[cfg(feature = "pem")]
-impl<C> ToString for PublicKey<C>
+impl<C> FromStr for PublicKey<C>
 where
     C: AssociatedOid + CurveArithmetic,
     AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
     FieldBytesSize<C>: ModulusSize,
 {
-    fn to_string(&self) -> String {
-        self.to_public_key_pem(Default::default())
-            .expect("PEM encoding error")
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s.starts_with("-----BEGIN ") {
+            Self::from_public_key_pem(s)
+        } else {
+            let hex = s.strip_prefix("0x").unwrap_or(s);
+            Self::from_sec1_bytes(&base16ct::mixed::decode_vec(hex)?)
+        }
     }
 }
 
 #[cfg(feature = "serde")]
 impl<C> Serialize for PublicKey<C>
 where
-    C: AssociatedOid + CurveArithmetic,
+    C: CurveArithmetic + PointCompression,
     AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
     FieldBytesSize<C>: ModulusSize,
 {
@@ -529,8 +1158,7 @@ where
     where
         S: ser::Serializer,
     {
-        let der = self.to_public_key_der().map_err(ser::Error::custom)?;
-        serdect::slice::serialize_hex_upper_or_bin(&der, serializer)
+        serdect::slice::serialize_hex_upper_or_bin(&self.to_sec1_bytes(), serializer)
     }
 }
 
@@ -545,14 +1173,100 @@ where
     where
         D: de::Deserializer<'de>,
     {
-        let der_bytes = serdect::slice::deserialize_hex_or_bin_vec(deserializer)?;
-        Self::from_public_key_der(&der_bytes).map_err(de::Error::custom)
+        let bytes = serdect::slice::deserialize_hex_or_bin_vec(deserializer)?;
+
+        // Previous versions serialized public keys as SPKI DER, which starts
+        // with a SEQUENCE tag that is never a valid SEC1 tag
+        if bytes.first() == Some(&0x30) {
+            Self::from_public_key_der(&bytes).map_err(de::Error::custom)
+        } else {
+            Self::from_sec1_bytes(&bytes).map_err(de::Error::custom)
+        }
+    }
+}
+
+/// `serde` support for [`PublicKey`] as a compact SEC1 point.
+///
+/// For use with `serde`'s field attributes on a [`PublicKey`] field:
+///
+/// ```text
+/// #[serde(with = "elliptic_curve::serde_compact")]
+/// public_key: PublicKey<NistP256>,
+/// ```
+///
+/// Keys whose y-coordinate is the canonical one chosen by [`DecompactPoint`]
+/// are serialized as compact points, i.e. just their x-coordinate with the
+/// `0x05` tag. All other keys fall back to the same encoding as the
+/// [`Serialize`] impl for [`PublicKey`], as they can't be compacted.
+///
+/// Deserialization accepts any SEC1 point, compact or not.
+///
+/// ```
+/// # #[cfg(feature = "dev")]
+/// # {
+/// use elliptic_curve::{dev::{AffinePoint, PublicKey}, sec1::ToEncodedPoint, serde_compact};
+///
+/// let public_key = PublicKey::from_affine(AffinePoint::Generator).unwrap();
+///
+/// let mut serialized = Vec::new();
+/// serde_compact::serialize(&public_key, &mut serde_json::Serializer::new(&mut serialized)).unwrap();
+/// assert_eq!(
+///     serialized,
+///     br#""056B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296""#
+/// );
+///
+/// let deserialized: PublicKey =
+///     serde_compact::deserialize(&mut serde_json::Deserializer::from_slice(&serialized)).unwrap();
+/// assert_eq!(deserialized.to_encoded_point(false), public_key.to_encoded_point(false));
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_compact {
+    use crate::{
+        point::PointCompression,
+        sec1::{FromEncodedPoint, ModulusSize, ToCompactEncodedPoint, ToEncodedPoint},
+        AffinePoint, CurveArithmetic, FieldBytesSize, PublicKey,
+    };
+    use serdect::serde::{de, ser};
+
+    /// Serialize a [`PublicKey`] as a compact SEC1 point if possible, or as
+    /// its [`PointCompression`] encoding otherwise.
+    pub fn serialize<C, S>(public_key: &PublicKey<C>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: CurveArithmetic + PointCompression,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C> + ToCompactEncodedPoint<C>,
+        FieldBytesSize<C>: ModulusSize,
+        S: ser::Serializer,
+    {
+        let encoded_point = Option::from(public_key.to_compact_encoded_point())
+            .unwrap_or_else(|| public_key.to_encoded_point(C::COMPRESS_POINTS));
+
+        serdect::slice::serialize_hex_upper_or_bin(&encoded_point, serializer)
+    }
+
+    /// Deserialize a [`PublicKey`] from a SEC1 point.
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<PublicKey<C>, D::Error>
+    where
+        C: CurveArithmetic,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+        FieldBytesSize<C>: ModulusSize,
+        D: de::Deserializer<'de>,
+    {
+        let bytes = serdect::slice::deserialize_hex_or_bin_vec(deserializer)?;
+        PublicKey::from_sec1_bytes(&bytes).map_err(de::Error::custom)
     }
 }
 
 #[cfg(all(feature = "dev", test))]
 mod tests {
-    use crate::{dev::MockCurve, sec1::FromEncodedPoint};
+    #![allow(clippy::unwrap_used)]
+    use crate::{
+        dev::{FieldBytes, MockCurve, NonZeroScalar, SecretKey},
+        point::DecompressPoint,
+        sec1::{FromEncodedPoint, ToEncodedPoint},
+    };
+    use hex_literal::hex;
+    use subtle::Choice;
 
     type EncodedPoint = crate::sec1::EncodedPoint<MockCurve>;
     type PublicKey = super::PublicKey<MockCurve>;
@@ -564,4 +1278,672 @@ mod tests {
             PublicKey::from_encoded_point(&identity).is_none()
         ));
     }
+
+    #[test]
+    fn compact_round_trip() {
+        use crate::sec1::ToCompactEncodedPoint;
+
+        // The generator's y-coordinate is the smaller root, so it's compactable
+        let generator = PublicKey::from_affine(crate::dev::AffinePoint::Generator).unwrap();
+        let compact = generator.to_compact_encoded_point().unwrap();
+        assert_eq!(compact.tag(), crate::sec1::Tag::Compact);
+        assert_eq!(compact.x().unwrap(), &GENERATOR_X);
+
+        let decoded = PublicKey::from_encoded_point(&compact).unwrap();
+        assert_eq!(
+            decoded.to_encoded_point(false),
+            generator.to_encoded_point(false)
+        );
+        assert_eq!(
+            PublicKey::from_sec1_bytes(compact.as_bytes()).unwrap(),
+            decoded
+        );
+    }
+
+    #[test]
+    fn compact_non_canonical() {
+        use crate::{point::DecompactPoint, sec1::ToCompactEncodedPoint};
+
+        // 3G has the larger of its two possible y-coordinates
+        let x = hex!("5ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c");
+        let y = hex!("8734640c4998ff7e374b06ce1a64a2ecd82ab036384fb83d9a79b127a27d5032");
+        let three_g = PublicKey::from_encoded_point(&EncodedPoint::from_affine_coordinates(
+            &x.into(),
+            &y.into(),
+            false,
+        ))
+        .unwrap();
+        assert!(bool::from(three_g.to_compact_encoded_point().is_none()));
+
+        // ...so decompacting its x-coordinate yields -3G
+        let neg_y = hex!("78cb9bf2b6670082c8b4f931e59b5d1327d54fcac7b047c265864ed85d82afcd");
+        let neg_three_g = PublicKey::decompact(&x.into()).unwrap();
+        assert_eq!(
+            neg_three_g.to_encoded_point(false),
+            EncodedPoint::from_affine_coordinates(&x.into(), &neg_y.into(), false)
+        );
+        assert!(bool::from(neg_three_g.to_compact_encoded_point().is_some()));
+    }
+
+    #[test]
+    fn decompact_rejects_x_not_on_curve() {
+        use crate::point::DecompactPoint;
+
+        let mut x = FieldBytes::default();
+        x[31] = 1;
+        assert!(bool::from(PublicKey::decompact(&x).is_none()));
+
+        let mut compact = [0u8; 33];
+        compact[0] = 0x05;
+        compact[32] = 1;
+        assert!(PublicKey::from_sec1_bytes(&compact).is_err());
+    }
+
+    #[test]
+    fn from_sec1_bytes_hybrid() {
+        let uncompressed = hex!("046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c2964fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5");
+        let expected = PublicKey::from_sec1_bytes(&uncompressed).unwrap();
+
+        // The generator's y-coordinate is odd
+        let mut hybrid = uncompressed;
+        hybrid[0] = 0x07;
+        assert_eq!(PublicKey::from_sec1_bytes(&hybrid).unwrap(), expected);
+
+        hybrid[0] = 0x06;
+        assert!(PublicKey::from_sec1_bytes(&hybrid).is_err());
+    }
+
+    #[test]
+    fn from_sec1_bytes_rejects_identity() {
+        assert!(PublicKey::from_sec1_bytes(&[0x00]).is_err());
+    }
+
+    #[test]
+    fn from_encoded_point_allowing_identity() {
+        let identity = EncodedPoint::from_bytes([0x00]).unwrap();
+        assert_eq!(
+            PublicKey::from_encoded_point_allowing_identity(&identity),
+            Ok(None)
+        );
+
+        let point = SecretKey::from_slice(&[0x42; 32])
+            .unwrap()
+            .public_key()
+            .to_encoded_point(false);
+        let public_key = PublicKey::from_encoded_point_allowing_identity(&point)
+            .unwrap()
+            .unwrap();
+        assert_eq!(public_key.to_encoded_point(false), point);
+    }
+
+    #[test]
+    fn from_encoded_point_allowing_identity_rejects_invalid() {
+        // x = 1 has no corresponding y-coordinate on the curve
+        let mut x = [0u8; 32];
+        x[31] = 1;
+        let point = EncodedPoint::from_affine_coordinates(&x.into(), &FieldBytes::default(), true);
+        assert!(PublicKey::from_encoded_point_allowing_identity(&point).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_from_encoded_points() {
+        let points = [0x42, 0x01, 0xAA, 0x17].map(|byte| {
+            SecretKey::from_slice(&[byte; 32])
+                .unwrap()
+                .public_key()
+                .to_encoded_point(false)
+        });
+
+        let public_keys = PublicKey::batch_from_encoded_points(&points).unwrap();
+        assert_eq!(public_keys.len(), points.len());
+        for (public_key, point) in public_keys.iter().zip(&points) {
+            assert_eq!(&public_key.to_encoded_point(false), point);
+        }
+
+        assert!(PublicKey::batch_from_encoded_points(&[])
+            .unwrap()
+            .is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_from_encoded_points_reports_first_invalid() {
+        let valid = SecretKey::from_slice(&[0x42; 32])
+            .unwrap()
+            .public_key()
+            .to_encoded_point(false);
+
+        for index in [0, 2, 4] {
+            let mut points = [valid; 5];
+            points[index] = EncodedPoint::identity();
+            assert_eq!(PublicKey::batch_from_encoded_points(&points), Err(index));
+
+            if index < 4 {
+                points[4] = EncodedPoint::identity();
+                assert_eq!(PublicKey::batch_from_encoded_points(&points), Err(index));
+            }
+        }
+    }
+
+    /// x-coordinate of the mock curve's generator, whose y-coordinate is odd.
+    const GENERATOR_X: [u8; 32] =
+        hex!("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296");
+
+    #[test]
+    fn from_x_only_chooses_even_y() {
+        let public_key = PublicKey::from_x_only(&GENERATOR_X.into()).unwrap();
+        assert!(bool::from(public_key.has_even_y()));
+        assert_eq!(public_key.to_x_only_bytes(), GENERATOR_X);
+
+        // Negation of the generator
+        let even_y = hex!("b01cbd1c01e58065711814b583f061e9d431cca994cea1313449bf97c840ae0a");
+        assert_eq!(
+            public_key.to_encoded_point(false),
+            EncodedPoint::from_affine_coordinates(&GENERATOR_X.into(), &even_y.into(), false)
+        );
+
+        // 3G already has an even y-coordinate
+        let x = hex!("5ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c");
+        let y = hex!("8734640c4998ff7e374b06ce1a64a2ecd82ab036384fb83d9a79b127a27d5032");
+        let public_key = PublicKey::from_x_only(&x.into()).unwrap();
+        assert_eq!(
+            public_key.to_encoded_point(false),
+            EncodedPoint::from_affine_coordinates(&x.into(), &y.into(), false)
+        );
+    }
+
+    #[test]
+    fn from_x_only_rejects_x_not_on_curve() {
+        let mut x = FieldBytes::default();
+        x[31] = 1;
+        assert!(bool::from(PublicKey::from_x_only(&x).is_none()));
+    }
+
+    #[test]
+    fn has_even_y() {
+        let odd = PublicKey::from_encoded_point(&EncodedPoint::from_affine_coordinates(
+            &GENERATOR_X.into(),
+            &hex!("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5").into(),
+            false,
+        ))
+        .unwrap();
+        assert!(!bool::from(odd.has_even_y()));
+    }
+
+    #[test]
+    fn x_only_round_trip() {
+        let mut round_trips = 0;
+
+        for i in 0..=u8::MAX {
+            let mut x = GENERATOR_X;
+            x[31] = i;
+
+            if let Some(public_key) = Option::<PublicKey>::from(PublicKey::from_x_only(&x.into())) {
+                let x_only = public_key.to_x_only_bytes();
+                assert_eq!(x_only, x);
+                assert_eq!(
+                    PublicKey::from_x_only(&x_only).unwrap().to_x_only_bytes(),
+                    x_only
+                );
+                round_trips += 1;
+            }
+        }
+
+        // Roughly half of all x-coordinates are on the curve
+        assert!(round_trips > 64);
+    }
+
+    #[test]
+    fn write_sec1() {
+        let public_key = PublicKey::from_affine(crate::dev::AffinePoint::Generator).unwrap();
+
+        for compress in [false, true] {
+            let point = public_key.to_encoded_point(compress);
+
+            let mut buf = [0u8; 65];
+            assert_eq!(
+                public_key.write_sec1(&mut buf, compress).unwrap(),
+                point.as_bytes()
+            );
+
+            let mut exact = [0u8; 65];
+            let exact = &mut exact[..point.len()];
+            assert_eq!(
+                public_key.write_sec1(exact, compress).unwrap(),
+                point.as_bytes()
+            );
+
+            let mut short = [0u8; 65];
+            assert!(public_key
+                .write_sec1(&mut short[..point.len() - 1], compress)
+                .is_err());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_sec1_bytes_matches_write_sec1() {
+        use crate::point::PointCompression;
+
+        let public_key = PublicKey::from_affine(crate::dev::AffinePoint::Generator).unwrap();
+        let mut buf = [0u8; 65];
+
+        assert_eq!(
+            &*public_key.to_sec1_bytes(),
+            public_key
+                .write_sec1(&mut buf, MockCurve::COMPRESS_POINTS)
+                .unwrap()
+        );
+        assert_eq!(
+            &*public_key.to_sec1_bytes(),
+            public_key
+                .to_encoded_point(MockCurve::COMPRESS_POINTS)
+                .as_bytes()
+        );
+    }
+
+    #[test]
+    fn add_point() {
+        let a = NonZeroScalar::from_repr(FieldBytes::from([0x42; 32])).unwrap();
+        let b = NonZeroScalar::from_repr(FieldBytes::from([0x24; 32])).unwrap();
+        let pk_a = PublicKey::from_secret_scalar(&a);
+        let pk_b = PublicKey::from_secret_scalar(&b);
+
+        // (a + b)G == aG + bG
+        let sum = NonZeroScalar::new(*a + *b).unwrap();
+        assert_eq!(
+            pk_a.add_point(&pk_b).unwrap(),
+            PublicKey::from_secret_scalar(&sum)
+        );
+
+        // Commutativity
+        assert_eq!(pk_a.add_point(&pk_b), pk_b.add_point(&pk_a));
+    }
+
+    #[test]
+    fn add_point_rejects_identity() {
+        let a = NonZeroScalar::from_repr(FieldBytes::from([0x42; 32])).unwrap();
+        let pk = PublicKey::from_secret_scalar(&a);
+        let neg_pk = PublicKey::from_secret_scalar(&-a);
+        assert!(pk.add_point(&neg_pk).is_err());
+    }
+
+    #[test]
+    fn combine() {
+        let scalars = [0x42, 0x24, 0x01]
+            .map(|byte| NonZeroScalar::from_repr(FieldBytes::from([byte; 32])).unwrap());
+        let keys = scalars.map(|scalar| PublicKey::from_secret_scalar(&scalar));
+
+        let sum = NonZeroScalar::new(*scalars[0] + *scalars[1] + *scalars[2]).unwrap();
+        assert_eq!(
+            PublicKey::combine(&keys).unwrap(),
+            PublicKey::from_secret_scalar(&sum)
+        );
+        assert_eq!(PublicKey::combine(&keys[..1]).unwrap(), keys[0]);
+        assert!(PublicKey::combine(&[]).is_err());
+    }
+
+    #[test]
+    fn ct_eq() {
+        use subtle::ConstantTimeEq;
+
+        /// The comparison relies on this bound, which every curve must meet
+        const fn assert_ct_eq<T: ConstantTimeEq>() {}
+        assert_ct_eq::<crate::dev::AffinePoint>();
+        assert_ct_eq::<PublicKey>();
+
+        let public_key = SecretKey::from_slice(&[0x42; 32]).unwrap().public_key();
+        let other = SecretKey::from_slice(&[0x24; 32]).unwrap().public_key();
+
+        assert!(bool::from(public_key.ct_eq(&public_key.clone())));
+        assert!(!bool::from(public_key.ct_eq(&other)));
+        assert_eq!(public_key, public_key.clone());
+        assert_ne!(public_key, other);
+    }
+
+    #[test]
+    fn display_generator() {
+        let public_key = PublicKey::from_affine(crate::dev::AffinePoint::Generator).unwrap();
+        let lower = "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296";
+        let upper = "036B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296";
+
+        assert_eq!(format!("{public_key}"), lower);
+        assert_eq!(format!("{public_key:x}"), lower);
+        assert_eq!(format!("{public_key:#x}"), format!("0x{lower}"));
+        assert_eq!(format!("{public_key:X}"), upper);
+        assert_eq!(format!("{public_key:#X}"), format!("0x{upper}"));
+    }
+
+    /// `to_string` used to return SPKI PEM, and now returns the hex encoding
+    /// of the compressed SEC1 point. PEM is available from
+    /// `to_public_key_pem`.
+    #[cfg(feature = "pem")]
+    #[test]
+    fn to_string_is_hex_not_pem() {
+        use alloc::string::ToString;
+
+        let public_key = PublicKey::from_affine(crate::dev::AffinePoint::Generator).unwrap();
+        let pem = public_key
+            .to_public_key_pem(pem_rfc7468::LineEnding::LF)
+            .unwrap();
+
+        assert_eq!(
+            public_key.to_string(),
+            "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+        );
+        assert!(pem.starts_with("-----BEGIN PUBLIC KEY-----"));
+        assert_ne!(public_key.to_string(), pem);
+    }
+
+    #[cfg(feature = "pem")]
+    #[test]
+    fn display_round_trip() {
+        let public_key = PublicKey::from_affine(crate::dev::AffinePoint::Generator).unwrap();
+
+        for s in [
+            format!("{public_key}"),
+            format!("{public_key:#x}"),
+            format!("{public_key:X}"),
+            format!("{public_key:#X}"),
+        ] {
+            let parsed = s.parse::<PublicKey>().unwrap();
+            assert_eq!(
+                parsed.to_encoded_point(true),
+                public_key.to_encoded_point(true)
+            );
+        }
+    }
+
+    #[test]
+    fn from_encoded_point_checked() {
+        let x = hex!("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296");
+        let mut y = hex!("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5");
+
+        let point = EncodedPoint::from_affine_coordinates(&x.into(), &y.into(), false);
+        let public_key = PublicKey::from_encoded_point_checked(&point).expect("valid point");
+        assert_eq!(public_key, PublicKey::from_encoded_point(&point).unwrap());
+
+        y[31] ^= 1;
+        let point = EncodedPoint::from_affine_coordinates(&x.into(), &y.into(), false);
+        assert!(PublicKey::from_encoded_point_checked(&point).is_err());
+
+        let identity = EncodedPoint::identity();
+        assert!(PublicKey::from_encoded_point_checked(&identity).is_err());
+    }
+
+    /// Compressed SEC1 encoding of the mock curve's generator.
+    const COMPRESSED_GENERATOR: [u8; 33] =
+        hex!("036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296");
+
+    /// Uncompressed SEC1 encoding of the mock curve's generator.
+    const UNCOMPRESSED_GENERATOR: [u8; 65] = hex!("046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c2964fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5");
+
+    #[test]
+    fn from_sec1_bytes_compressed() {
+        let public_key = PublicKey::from_sec1_bytes(&COMPRESSED_GENERATOR).unwrap();
+        assert_eq!(
+            public_key.to_encoded_point(false).as_bytes(),
+            &UNCOMPRESSED_GENERATOR[..]
+        );
+        assert_eq!(
+            public_key.to_encoded_point(true).as_bytes(),
+            &COMPRESSED_GENERATOR[..]
+        );
+
+        // The other root of the curve equation is the generator's negation
+        let mut bytes = COMPRESSED_GENERATOR;
+        bytes[0] = 0x02;
+        let negated = PublicKey::from_sec1_bytes(&bytes).unwrap();
+        assert_eq!(
+            negated.to_encoded_point(false).x(),
+            public_key.to_encoded_point(false).x()
+        );
+        assert_ne!(
+            negated.to_encoded_point(false).y(),
+            public_key.to_encoded_point(false).y()
+        );
+    }
+
+    #[test]
+    fn from_sec1_bytes_compressed_not_on_curve() {
+        // `x^3 + a*x + b` is a quadratic non-residue for `x = 1`
+        let mut bytes = [0u8; 33];
+        bytes[0] = 0x02;
+        bytes[32] = 1;
+        assert!(PublicKey::from_sec1_bytes(&bytes).is_err());
+
+        bytes[0] = 0x03;
+        assert!(PublicKey::from_sec1_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn decompress() {
+        let x = FieldBytes::from_slice(&COMPRESSED_GENERATOR[1..]);
+        let public_key = PublicKey::decompress(x, Choice::from(1)).unwrap();
+        assert_eq!(
+            public_key.to_encoded_point(false).as_bytes(),
+            &UNCOMPRESSED_GENERATOR[..]
+        );
+    }
+
+    #[test]
+    fn ord_matches_compressed_encoding() {
+        let mut public_keys = [0x42, 0x01, 0xAA, 0x17]
+            .map(|byte| SecretKey::from_slice(&[byte; 32]).unwrap().public_key());
+        public_keys.sort();
+
+        for pair in public_keys.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert!(
+                pair[0].to_encoded_point(true).as_bytes()
+                    < pair[1].to_encoded_point(true).as_bytes()
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_matches_eq() {
+        use std::{
+            collections::hash_map::DefaultHasher,
+            hash::{Hash, Hasher},
+        };
+
+        fn hash(public_key: &PublicKey) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            public_key.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let public_key = SecretKey::from_slice(&[0x42; 32]).unwrap().public_key();
+        let other = SecretKey::from_slice(&[0x24; 32]).unwrap().public_key();
+        assert_eq!(hash(&public_key), hash(&public_key.clone()));
+        assert_ne!(hash(&public_key), hash(&other));
+    }
+
+    /// Subgroup checks on a curve whose cofactor isn't 1.
+    mod cofactor {
+        use super::{MockCurve, SecretKey};
+        use crate::{
+            dev::{AffinePoint, MockCurveVariant},
+            sec1::EncodedPoint,
+        };
+        use group::Curve as _;
+
+        type PublicKey = crate::PublicKey<MockCurveVariant<4, false>>;
+
+        fn small_order_component() -> AffinePoint {
+            AffinePoint::Other(EncodedPoint::<MockCurve>::from_affine_coordinates(
+                &[0x11; 32].into(),
+                &[0x22; 32].into(),
+                false,
+            ))
+        }
+
+        #[test]
+        fn from_affine_checks_subgroup() {
+            let secret_key = SecretKey::from_slice(&[0x42; 32]).unwrap();
+            let point = secret_key.public_key().to_projective().to_affine();
+            assert!(PublicKey::from_affine(point).is_ok());
+            assert!(PublicKey::from_affine(AffinePoint::Generator).is_ok());
+            assert!(PublicKey::from_affine(small_order_component()).is_err());
+        }
+
+        #[test]
+        fn from_affine_unchecked_skips_subgroup() {
+            let point = small_order_component();
+            let public_key = PublicKey::from_affine_unchecked(point).unwrap();
+            assert_eq!(public_key.as_affine(), &point);
+            assert!(PublicKey::from_affine_unchecked(AffinePoint::Identity).is_err());
+        }
+
+        #[test]
+        fn prime_order_curve_skips_subgroup() {
+            // The mock can't multiply `Other` points by the order, so this
+            // only succeeds because the check is skipped for a cofactor of 1.
+            let point = small_order_component();
+            assert!(super::PublicKey::from_affine(point).is_ok());
+        }
+    }
+
+    /// Serialization on a curve which uses point compression.
+    #[cfg(feature = "serde")]
+    mod compressed {
+        use crate::dev::MockCurveVariant;
+        use hex_literal::hex;
+
+        type PublicKey = crate::PublicKey<MockCurveVariant<1, true>>;
+
+        /// Uncompressed SEC1 encoding of the example public key.
+        const PUBLIC_KEY_BYTES: [u8; 65] = hex!("041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F");
+
+        /// Compressed SEC1 encoding of the example public key.
+        const COMPRESSED_PUBLIC_KEY_BYTES: [u8; 33] =
+            hex!("031CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7F");
+
+        #[test]
+        fn serialize_compressed() {
+            let public_key = PublicKey::from_sec1_bytes(&PUBLIC_KEY_BYTES).unwrap();
+
+            let json = serde_json::to_string(&public_key).unwrap();
+            assert_eq!(
+                json,
+                "\"031CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7F\""
+            );
+            assert_eq!(
+                serde_json::from_str::<PublicKey>(&json).unwrap(),
+                public_key
+            );
+
+            let bytes = bincode::serialize(&public_key).unwrap();
+            assert_eq!(
+                bytes,
+                bincode::serialize(&COMPRESSED_PUBLIC_KEY_BYTES[..]).unwrap()
+            );
+            assert_eq!(
+                bincode::deserialize::<PublicKey>(&bytes).unwrap(),
+                public_key
+            );
+        }
+    }
+
+    /// Serialization of compact points with `serde_compact`.
+    #[cfg(feature = "serde")]
+    mod serde_compact {
+        use super::{EncodedPoint, PublicKey, GENERATOR_X};
+        use crate::{
+            dev::{AffinePoint, MockCurveVariant},
+            sec1::{FromEncodedPoint, Tag, ToEncodedPoint},
+        };
+        use alloc::{string::String, vec::Vec};
+        use hex_literal::hex;
+
+        fn to_json(public_key: &PublicKey) -> String {
+            let mut json = Vec::new();
+            crate::serde_compact::serialize(
+                public_key,
+                &mut serde_json::Serializer::new(&mut json),
+            )
+            .unwrap();
+            String::from_utf8(json).unwrap()
+        }
+
+        fn from_json(json: &str) -> serde_json::Result<PublicKey> {
+            crate::serde_compact::deserialize(&mut serde_json::Deserializer::from_str(json))
+        }
+
+        /// 3G, whose y-coordinate isn't the canonical one.
+        fn three_g() -> EncodedPoint {
+            EncodedPoint::from_affine_coordinates(
+                &hex!("5ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c").into(),
+                &hex!("8734640c4998ff7e374b06ce1a64a2ecd82ab036384fb83d9a79b127a27d5032").into(),
+                false,
+            )
+        }
+
+        #[test]
+        fn json_round_trip() {
+            let public_key = PublicKey::from_affine(AffinePoint::Generator).unwrap();
+            let json = to_json(&public_key);
+
+            let encoded = EncodedPoint::from_bytes(
+                base16ct::upper::decode_vec(json.trim_matches('"')).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(encoded.tag(), Tag::Compact);
+            assert_eq!(encoded.x().unwrap(), &GENERATOR_X);
+            assert_eq!(
+                from_json(&json).unwrap().to_encoded_point(false),
+                public_key.to_encoded_point(false)
+            );
+        }
+
+        #[test]
+        fn binary_round_trip() {
+            let public_key = PublicKey::from_affine(AffinePoint::Generator).unwrap();
+
+            let mut bytes = Vec::new();
+            crate::serde_compact::serialize(
+                &public_key,
+                &mut bincode::Serializer::new(&mut bytes, bincode::options()),
+            )
+            .unwrap();
+
+            // Length prefix followed by the compact SEC1 point
+            assert_eq!(bytes[1], 0x05);
+            assert_eq!(&bytes[2..], &GENERATOR_X[..]);
+
+            let decoded: PublicKey = crate::serde_compact::deserialize(
+                &mut bincode::Deserializer::from_slice(&bytes, bincode::options()),
+            )
+            .unwrap();
+            assert_eq!(
+                decoded.to_encoded_point(false),
+                public_key.to_encoded_point(false)
+            );
+        }
+
+        #[test]
+        fn non_canonical_falls_back() {
+            let public_key = PublicKey::from_encoded_point(&three_g()).unwrap();
+            let json = to_json(&public_key);
+            assert_eq!(json, serde_json::to_string(&public_key).unwrap());
+            assert_eq!(from_json(&json).unwrap(), public_key);
+
+            // Curves with point compression fall back to compressed points
+            let public_key = crate::PublicKey::<MockCurveVariant<1, true>>::from_sec1_bytes(
+                three_g().as_bytes(),
+            )
+            .unwrap();
+            let mut json = Vec::new();
+            crate::serde_compact::serialize(
+                &public_key,
+                &mut serde_json::Serializer::new(&mut json),
+            )
+            .unwrap();
+            assert_eq!(&json[..3], b"\"02");
+            assert_eq!(json, serde_json::to_vec(&public_key).unwrap());
+        }
+    }
 }
@@ -2,17 +2,26 @@
 
 #[cfg(feature = "arithmetic")]
 mod blinded;
+mod montgomery;
 #[cfg(feature = "arithmetic")]
 mod nonzero;
+mod nonzero_primitive;
 mod primitive;
 
-pub use self::primitive::ScalarPrimitive;
+pub use self::{
+    montgomery::ScalarPrimitiveMontgomery, nonzero_primitive::NonZeroScalarPrimitive,
+    primitive::ScalarPrimitive,
+};
 
 #[cfg(feature = "arithmetic")]
 pub use self::{blinded::BlindedScalar, nonzero::NonZeroScalar};
 
+use core::iter;
 use crypto_bigint::Integer;
-use subtle::Choice;
+use subtle::{Choice, CtOption};
+
+#[cfg(feature = "arithmetic")]
+use subtle::ConstantTimeGreater;
 
 #[cfg(feature = "arithmetic")]
 use crate::CurveArithmetic;
@@ -42,6 +51,30 @@ pub trait FromUintUnchecked {
     fn from_uint_unchecked(uint: Self::Uint) -> Self;
 }
 
+/// Instantiate a scalar from an unsigned integer, checking that it's in range.
+///
+/// With the `arithmetic` feature this is implemented for every [`ff::Field`]
+/// which implements [`FromUintUnchecked`] and converts into its `Uint`, which
+/// includes the scalar of every [`CurveArithmetic`] implementation.
+pub trait FromUint: FromUintUnchecked + Sized {
+    /// Instantiate scalar from an unsigned integer, returning `None` if the
+    /// value overflows the field modulus.
+    fn from_uint(uint: Self::Uint) -> CtOption<Self>;
+}
+
+/// The range check compares `uint` against `-1`, i.e. the modulus minus one.
+#[cfg(feature = "arithmetic")]
+impl<T> FromUint for T
+where
+    T: FromUintUnchecked + ff::Field + Into<<T as FromUintUnchecked>::Uint>,
+{
+    fn from_uint(uint: T::Uint) -> CtOption<Self> {
+        let max: T::Uint = (-T::ONE).into();
+        let in_range = !uint.ct_gt(&max);
+        CtOption::new(T::from_uint_unchecked(uint), in_range)
+    }
+}
+
 /// Is this scalar greater than n / 2?
 ///
 /// # Returns
@@ -49,6 +82,62 @@ pub trait FromUintUnchecked {
 /// - For scalars 0 through n / 2: `Choice::from(0)`
 /// - For scalars (n / 2) + 1 through n - 1: `Choice::from(1)`
 pub trait IsHigh {
-    /// Is this scalar greater than or equal to n / 2?
+    /// Is this scalar greater than n / 2?
     fn is_high(&self) -> Choice;
 }
+
+/// Compute the width-`w` non-adjacent form (wNAF) of a scalar, given as a
+/// function returning its bits in little-endian order.
+///
+/// Produces `len` signed digits starting from the least significant, where
+/// `len` must exceed the number of bits in the scalar by at least one. Every
+/// non-zero digit is odd, lies in `(-2^(w-1), 2^(w-1))`, and is followed by at
+/// least `w - 1` zero digits.
+///
+/// `w` must be in the range `2..=8`, and `bit` is only called for indices
+/// below `len + w - 1`.
+pub(crate) fn wnaf_vartime(
+    bit: impl Fn(usize) -> bool,
+    len: usize,
+    w: usize,
+) -> impl Iterator<Item = i8> {
+    debug_assert!((2..=8).contains(&w));
+
+    let width = 1i16 << w;
+    let mut pos = 0;
+    let mut carry = 0i16;
+    let mut zeros = 0;
+
+    iter::from_fn(move || {
+        if pos >= len {
+            return None;
+        }
+
+        let start = pos;
+        pos += 1;
+
+        if zeros > 0 {
+            zeros -= 1;
+            return Some(0);
+        }
+
+        let window = (0..w)
+            .filter(|&i| bit(start + i))
+            .fold(carry, |acc, i| acc + (1 << i));
+
+        if window & 1 == 0 {
+            return Some(0);
+        }
+
+        let digit = if window < width / 2 {
+            carry = 0;
+            window
+        } else {
+            carry = 1;
+            window - width
+        };
+
+        zeros = w - 1;
+        i8::try_from(digit).ok()
+    })
+}
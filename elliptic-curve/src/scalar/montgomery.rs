@@ -0,0 +1,387 @@
+//! Montgomery form representation of [`ScalarPrimitive`].
+
+use super::ScalarPrimitive;
+use crate::{
+    bigint::{Integer, Monty, Square},
+    Curve,
+};
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
+
+/// Montgomery form of `C::Uint` modulo the curve's order.
+type MontyForm<C> = <<C as Curve>::Uint as Integer>::Monty;
+
+/// [`ScalarPrimitive`] in Montgomery form.
+///
+/// Each multiplication of [`ScalarPrimitive`]s already multiplies in
+/// Montgomery form, but first computes the Montgomery parameters for the
+/// curve order and converts both operands into Montgomery form, and then
+/// converts the product back out. This type stays in Montgomery form with its
+/// parameters computed once, so a sequence of multiplications only pays for
+/// the setup and conversions once in each direction.
+///
+/// Use [`ScalarPrimitive::to_montgomery`] and
+/// [`ScalarPrimitive::from_montgomery`] to convert between representations.
+///
+/// The value is zeroized on drop.
+#[derive(Clone)]
+pub struct ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    /// Inner Montgomery form integer.
+    inner: MontyForm<C>,
+}
+
+impl<C> ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    /// Wrap a Montgomery form integer.
+    pub(super) fn from_monty(inner: MontyForm<C>) -> Self {
+        Self { inner }
+    }
+
+    /// Borrow the inner Montgomery form integer.
+    pub(super) fn as_monty(&self) -> &MontyForm<C> {
+        &self.inner
+    }
+
+    /// Compute the square of this scalar.
+    pub fn square(&self) -> Self {
+        Self {
+            inner: self.inner.square(),
+        }
+    }
+}
+
+impl<C> From<ScalarPrimitive<C>> for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn from(scalar: ScalarPrimitive<C>) -> Self {
+        scalar.to_montgomery()
+    }
+}
+
+impl<C> From<&ScalarPrimitive<C>> for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn from(scalar: &ScalarPrimitive<C>) -> Self {
+        scalar.to_montgomery()
+    }
+}
+
+impl<C> From<ScalarPrimitiveMontgomery<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn from(scalar: ScalarPrimitiveMontgomery<C>) -> Self {
+        Self::from_montgomery(&scalar)
+    }
+}
+
+impl<C> From<&ScalarPrimitiveMontgomery<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn from(scalar: &ScalarPrimitiveMontgomery<C>) -> Self {
+        Self::from_montgomery(scalar)
+    }
+}
+
+impl<C> ConstantTimeEq for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.inner
+            .as_montgomery()
+            .ct_eq(other.inner.as_montgomery())
+    }
+}
+
+impl<C> Eq for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+}
+
+impl<C> PartialEq for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+/// Prints the canonical value of the scalar like [`ScalarPrimitive`] does,
+/// rather than its Montgomery representation.
+impl<C> fmt::Debug for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ScalarPrimitiveMontgomery({:#X})",
+            ScalarPrimitive::from(self)
+        )
+    }
+}
+
+impl<C> Add<ScalarPrimitiveMontgomery<C>> for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.add(&other)
+    }
+}
+
+impl<C> Add<&ScalarPrimitiveMontgomery<C>> for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    type Output = Self;
+
+    fn add(self, other: &Self) -> Self {
+        Self {
+            inner: self.inner.clone() + &other.inner,
+        }
+    }
+}
+
+impl<C> AddAssign<ScalarPrimitiveMontgomery<C>> for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.inner += &other.inner;
+    }
+}
+
+impl<C> AddAssign<&ScalarPrimitiveMontgomery<C>> for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn add_assign(&mut self, other: &Self) {
+        self.inner += &other.inner;
+    }
+}
+
+impl<C> Sub<ScalarPrimitiveMontgomery<C>> for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.sub(&other)
+    }
+}
+
+impl<C> Sub<&ScalarPrimitiveMontgomery<C>> for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    type Output = Self;
+
+    fn sub(self, other: &Self) -> Self {
+        Self {
+            inner: self.inner.clone() - &other.inner,
+        }
+    }
+}
+
+impl<C> SubAssign<ScalarPrimitiveMontgomery<C>> for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.inner -= &other.inner;
+    }
+}
+
+impl<C> SubAssign<&ScalarPrimitiveMontgomery<C>> for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn sub_assign(&mut self, other: &Self) {
+        self.inner -= &other.inner;
+    }
+}
+
+impl<C> Mul<ScalarPrimitiveMontgomery<C>> for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.mul(&other)
+    }
+}
+
+impl<C> Mul<&ScalarPrimitiveMontgomery<C>> for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    type Output = Self;
+
+    fn mul(self, other: &Self) -> Self {
+        Self {
+            inner: self.inner.clone() * &other.inner,
+        }
+    }
+}
+
+impl<C> MulAssign<ScalarPrimitiveMontgomery<C>> for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn mul_assign(&mut self, other: Self) {
+        self.inner *= &other.inner;
+    }
+}
+
+impl<C> MulAssign<&ScalarPrimitiveMontgomery<C>> for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn mul_assign(&mut self, other: &Self) {
+        self.inner *= &other.inner;
+    }
+}
+
+impl<C> Neg for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            inner: -self.inner.clone(),
+        }
+    }
+}
+
+impl<C> Neg for &ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    type Output = ScalarPrimitiveMontgomery<C>;
+
+    fn neg(self) -> ScalarPrimitiveMontgomery<C> {
+        ScalarPrimitiveMontgomery {
+            inner: -self.inner.clone(),
+        }
+    }
+}
+
+impl<C> Zeroize for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.inner.zeroize();
+    }
+}
+
+impl<C> Drop for ScalarPrimitiveMontgomery<C>
+where
+    C: Curve,
+    MontyForm<C>: Zeroize,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use crate::dev::ScalarPrimitive;
+    use rand_core::OsRng;
+
+    #[test]
+    fn round_trip() {
+        for scalar in [
+            ScalarPrimitive::ZERO,
+            ScalarPrimitive::ONE,
+            -ScalarPrimitive::ONE,
+            ScalarPrimitive::random(&mut OsRng),
+        ] {
+            let montgomery = scalar.to_montgomery();
+            assert_eq!(ScalarPrimitive::from_montgomery(&montgomery), scalar);
+            assert_eq!(ScalarPrimitive::from(montgomery), scalar);
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = ScalarPrimitive::random(&mut OsRng);
+        let b = ScalarPrimitive::random(&mut OsRng);
+        let (am, bm) = (a.to_montgomery(), b.to_montgomery());
+
+        assert_eq!(ScalarPrimitive::from(am.clone() + &bm), a + b);
+        assert_eq!(ScalarPrimitive::from(am.clone() - &bm), a - b);
+        assert_eq!(ScalarPrimitive::from(am.clone() * &bm), a * b);
+        assert_eq!(ScalarPrimitive::from(-&am), -a);
+        assert_eq!(ScalarPrimitive::from(am.square()), a * a);
+
+        let mut acc = ScalarPrimitive::ONE.to_montgomery();
+        let mut expected = ScalarPrimitive::ONE;
+
+        for _ in 0..16 {
+            acc *= &am;
+            acc += &bm;
+            expected = expected * a + b;
+        }
+
+        assert_eq!(ScalarPrimitive::from(&acc), expected);
+        assert_eq!(acc, expected.to_montgomery());
+    }
+
+    #[test]
+    fn debug_prints_canonical_value() {
+        let scalar = ScalarPrimitive::random(&mut OsRng);
+        assert_eq!(
+            format!("{:?}", scalar.to_montgomery()),
+            format!("ScalarPrimitiveMontgomery({scalar:#X})")
+        );
+    }
+}
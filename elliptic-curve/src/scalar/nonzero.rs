@@ -3,20 +3,23 @@
 use crate::{
     ops::{Invert, Reduce, ReduceNonZero},
     scalar::IsHigh,
-    CurveArithmetic, Error, FieldBytes, PrimeCurve, Scalar, ScalarPrimitive, SecretKey,
+    CurveArithmetic, Error, FieldBytes, FieldBytesSize, PrimeCurve, Scalar, ScalarPrimitive,
+    SecretKey, WideBytes,
 };
-use base16ct::HexDisplay;
 use core::{
     fmt,
-    ops::{Deref, Mul, Neg},
+    ops::{Add, Deref, Mul, MulAssign, Neg},
     str,
 };
 use crypto_bigint::{ArrayEncoding, Integer};
 use ff::{Field, PrimeField};
-use hybrid_array::{typenum::Unsigned, Array};
-use rand_core::CryptoRngCore;
+use hybrid_array::{
+    typenum::{Sum, Unsigned},
+    Array, ArraySize,
+};
+use rand_core::{CryptoRngCore, RngCore};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
 
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
@@ -29,6 +32,35 @@ use serdect::serde::{de, ser, Deserialize, Serialize};
 ///
 /// In the context of ECC, it's useful for ensuring that scalar multiplication
 /// cannot result in the point at infinity.
+///
+/// # Arithmetic
+///
+/// Operations which preserve the non-zero invariant (multiplication for
+/// prime order curves, negation, and inversion) return [`NonZeroScalar`].
+/// Everything else is available by dereferencing to the inner [`Scalar`],
+/// which is free:
+///
+/// ```
+/// # #[cfg(feature = "dev")]
+/// # {
+/// use elliptic_curve::{dev::{NonZeroScalar, Scalar}, ff::Field, rand_core::OsRng};
+///
+/// let a = NonZeroScalar::random(&mut OsRng);
+/// let b = NonZeroScalar::random(&mut OsRng);
+/// let c = NonZeroScalar::random(&mut OsRng);
+/// let x = Scalar::random(&mut OsRng);
+///
+/// let s: Scalar = *-(a * b) * x + *c;
+/// assert_eq!(s, -(*a * *b * x) + *c);
+/// # }
+/// ```
+///
+/// # Zeroization
+///
+/// This type is [`Copy`], so it can't wipe itself on drop. Secret values
+/// such as private keys and nonces should be wrapped in [`Zeroizing`].
+/// [`Zeroize`] overwrites the value with one rather than zero to uphold
+/// the non-zero invariant.
 #[derive(Clone)]
 pub struct NonZeroScalar<C>
 where
@@ -53,11 +85,49 @@ where
         }
     }
 
+    /// Generate a random `NonZeroScalar`, returning an error if `rng` fails.
+    ///
+    /// This samples with the curve's [`Field::random`] impl like
+    /// [`NonZeroScalar::random`], so it returns the same scalar for the same
+    /// RNG output. The first error returned by [`RngCore::try_fill_bytes`]
+    /// ends sampling and is propagated rather than causing a panic.
+    ///
+    /// [`RngCore::try_fill_bytes`]: rand_core::RngCore::try_fill_bytes
+    pub fn try_random(rng: &mut impl CryptoRngCore) -> Result<Self, rand_core::Error> {
+        let mut rng = TryRng { rng, error: None };
+
+        loop {
+            let scalar = Scalar::<C>::random(&mut rng);
+
+            if let Some(error) = rng.error.take() {
+                return Err(error);
+            }
+
+            if let Some(result) = Self::new(scalar).into() {
+                return Ok(result);
+            }
+        }
+    }
+
     /// Create a [`NonZeroScalar`] from a scalar.
     pub fn new(scalar: Scalar<C>) -> CtOption<Self> {
         CtOption::new(Self { scalar }, !scalar.is_zero())
     }
 
+    /// Create a [`NonZeroScalar`] from a scalar without checking that it's
+    /// non-zero.
+    ///
+    /// This is useful in hot paths where the value is already known to be
+    /// non-zero, e.g. when it's the product or inverse of non-zero scalars.
+    ///
+    /// Passing zero is a logic error which breaks this type's invariant (and
+    /// thus e.g. makes [`NonZeroScalar::invert`] return zero), but it isn't
+    /// memory unsafe. Debug builds panic if `scalar` is zero.
+    pub fn new_unchecked(scalar: Scalar<C>) -> Self {
+        debug_assert!(!bool::from(scalar.is_zero()), "scalar is zero");
+        Self { scalar }
+    }
+
     /// Decode a [`NonZeroScalar`] from a big endian-serialized field element.
     pub fn from_repr(repr: FieldBytes<C>) -> CtOption<Self> {
         Scalar::<C>::from_repr(repr).and_then(Self::new)
@@ -67,6 +137,56 @@ where
     pub fn from_uint(uint: C::Uint) -> CtOption<Self> {
         ScalarPrimitive::new(uint).and_then(|scalar| Self::new(scalar.into()))
     }
+
+    /// Create a [`NonZeroScalar`] by reducing an integer twice the size of a
+    /// serialized field element modulo the curve's order.
+    ///
+    /// The input is interpreted as a big endian integer (see
+    /// [`ScalarPrimitive::from_be_bytes_reduced`]). The result is biased from
+    /// uniform by a negligible amount, which makes this suitable for deriving
+    /// nonces and blinding factors from hash output, e.g. with RFC 9380
+    /// `hash_to_field` or FROST's `H3`.
+    ///
+    /// If the input reduces to zero (which happens with negligible
+    /// probability for hash output) the result is one. This keeps the
+    /// function infallible and constant-time, and makes its output a
+    /// deterministic function of its input.
+    pub fn from_wide_bytes(bytes: &WideBytes<C>) -> Self
+    where
+        FieldBytesSize<C>: Add,
+        Sum<FieldBytesSize<C>, FieldBytesSize<C>>: ArraySize,
+    {
+        let scalar = Scalar::<C>::from(ScalarPrimitive::from_be_bytes_reduced(bytes));
+        Self {
+            scalar: Scalar::<C>::conditional_select(&scalar, &Scalar::<C>::ONE, scalar.is_zero()),
+        }
+    }
+
+    /// Create a [`NonZeroScalar`] from a `C::Uint`, reducing it modulo the
+    /// curve's order first.
+    ///
+    /// Returns `None` if the reduced value is zero.
+    pub fn from_uint_reduced(uint: C::Uint) -> CtOption<Self> {
+        Self::new(ScalarPrimitive::from_uint_reduced(uint).into())
+    }
+
+    /// Compute the multiplicative inverse of this scalar in constant time.
+    ///
+    /// Unlike inverting the inner [`Scalar`], this can't fail since the
+    /// value is guaranteed to be non-zero.
+    pub fn invert(&self) -> Self {
+        Invert::invert(self)
+    }
+
+    /// Compute the multiplicative inverse of this scalar in variable time.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This method should not be used with secret values, as its variable-time
+    /// operation can potentially leak secrets through sidechannels.
+    pub fn invert_vartime(&self) -> Self {
+        Invert::invert_vartime(self)
+    }
 }
 
 impl<C> AsRef<Scalar<C>> for NonZeroScalar<C>
@@ -162,9 +282,7 @@ where
     C: CurveArithmetic,
 {
     fn from(sk: &SecretKey<C>) -> NonZeroScalar<C> {
-        let scalar = sk.as_scalar_primitive().to_scalar();
-        debug_assert!(!bool::from(scalar.is_zero()));
-        Self { scalar }
+        Self::new_unchecked(sk.as_scalar_primitive().to_scalar())
     }
 }
 
@@ -176,16 +294,22 @@ where
     type Output = Self;
 
     fn invert(&self) -> Self {
+        let scalar = Invert::invert(&self.scalar);
+        debug_assert!(bool::from(scalar.is_some()));
+
         Self {
             // This will always succeed since `scalar` will never be 0
-            scalar: Invert::invert(&self.scalar).unwrap(),
+            scalar: scalar.unwrap(),
         }
     }
 
     fn invert_vartime(&self) -> Self::Output {
+        let scalar = Invert::invert_vartime(&self.scalar);
+        debug_assert!(bool::from(scalar.is_some()));
+
         Self {
             // This will always succeed since `scalar` will never be 0
-            scalar: Invert::invert_vartime(&self.scalar).unwrap(),
+            scalar: scalar.unwrap(),
         }
     }
 }
@@ -206,9 +330,18 @@ where
     type Output = NonZeroScalar<C>;
 
     fn neg(self) -> NonZeroScalar<C> {
-        let scalar = -self.scalar;
-        debug_assert!(!bool::from(scalar.is_zero()));
-        NonZeroScalar { scalar }
+        NonZeroScalar::new_unchecked(-self.scalar)
+    }
+}
+
+impl<C> Neg for &NonZeroScalar<C>
+where
+    C: CurveArithmetic,
+{
+    type Output = NonZeroScalar<C>;
+
+    fn neg(self) -> NonZeroScalar<C> {
+        -*self
     }
 }
 
@@ -233,9 +366,25 @@ where
     fn mul(self, other: &Self) -> Self {
         // Multiplication is modulo a prime, so the product of two non-zero
         // scalars is also non-zero.
-        let scalar = self.scalar * other.scalar;
-        debug_assert!(!bool::from(scalar.is_zero()));
-        NonZeroScalar { scalar }
+        NonZeroScalar::new_unchecked(self.scalar * other.scalar)
+    }
+}
+
+impl<C> MulAssign<NonZeroScalar<C>> for NonZeroScalar<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<C> MulAssign<&NonZeroScalar<C>> for NonZeroScalar<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+{
+    fn mul_assign(&mut self, other: &Self) {
+        *self = *self * other;
     }
 }
 
@@ -249,15 +398,11 @@ where
     type Bytes = <Scalar<C> as Reduce<I>>::Bytes;
 
     fn reduce(n: I) -> Self {
-        let scalar = Scalar::<C>::reduce_nonzero(n);
-        debug_assert!(!bool::from(scalar.is_zero()));
-        Self { scalar }
+        Self::new_unchecked(Scalar::<C>::reduce_nonzero(n))
     }
 
     fn reduce_bytes(bytes: &Self::Bytes) -> Self {
-        let scalar = Scalar::<C>::reduce_nonzero_bytes(bytes);
-        debug_assert!(!bool::from(scalar.is_zero()));
-        Self { scalar }
+        Self::new_unchecked(Scalar::<C>::reduce_nonzero_bytes(bytes))
     }
 }
 
@@ -278,6 +423,30 @@ where
     }
 }
 
+/// Fails only if the scalar is zero.
+impl<C> TryFrom<ScalarPrimitive<C>> for NonZeroScalar<C>
+where
+    C: CurveArithmetic,
+{
+    type Error = Error;
+
+    fn try_from(scalar: ScalarPrimitive<C>) -> Result<Self, Error> {
+        Self::try_from(&scalar)
+    }
+}
+
+/// Fails only if the scalar is zero.
+impl<C> TryFrom<&ScalarPrimitive<C>> for NonZeroScalar<C>
+where
+    C: CurveArithmetic,
+{
+    type Error = Error;
+
+    fn try_from(scalar: &ScalarPrimitive<C>) -> Result<Self, Error> {
+        Option::from(Self::new(scalar.to_scalar())).ok_or(Error)
+    }
+}
+
 impl<C> TryFrom<&[u8]> for NonZeroScalar<C>
 where
     C: CurveArithmetic,
@@ -307,12 +476,13 @@ where
     }
 }
 
+/// Formatting is the same as for [`ScalarPrimitive`].
 impl<C> fmt::Display for NonZeroScalar<C>
 where
     C: CurveArithmetic,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{self:X}")
+        fmt::UpperHex::fmt(self, f)
     }
 }
 
@@ -321,7 +491,7 @@ where
     C: CurveArithmetic,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:x}", HexDisplay(&self.to_repr()))
+        fmt::LowerHex::fmt(&*Zeroizing::new(ScalarPrimitive::from(self)), f)
     }
 }
 
@@ -330,10 +500,12 @@ where
     C: CurveArithmetic,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:}", HexDisplay(&self.to_repr()))
+        fmt::UpperHex::fmt(&*Zeroizing::new(ScalarPrimitive::from(self)), f)
     }
 }
 
+/// Parses the same big endian hex format as [`ScalarPrimitive`], and
+/// additionally rejects zero.
 impl<C> str::FromStr for NonZeroScalar<C>
 where
     C: CurveArithmetic,
@@ -341,13 +513,8 @@ where
     type Err = Error;
 
     fn from_str(hex: &str) -> Result<Self, Error> {
-        let mut bytes = FieldBytes::<C>::default();
-
-        if base16ct::mixed::decode(hex, &mut bytes)?.len() == bytes.len() {
-            Option::from(Self::from_repr(bytes)).ok_or(Error)
-        } else {
-            Err(Error)
-        }
+        let scalar = Zeroizing::new(ScalarPrimitive::<C>::from_str(hex)?);
+        Option::from(Self::new((*scalar).into())).ok_or(Error)
     }
 }
 
@@ -360,7 +527,7 @@ where
     where
         S: ser::Serializer,
     {
-        ScalarPrimitive::from(self).serialize(serializer)
+        Zeroizing::new(ScalarPrimitive::from(self)).serialize(serializer)
     }
 }
 
@@ -373,19 +540,87 @@ where
     where
         D: de::Deserializer<'de>,
     {
-        let scalar = ScalarPrimitive::deserialize(deserializer)?;
-        Option::from(Self::new(scalar.into()))
+        let scalar = Zeroizing::new(ScalarPrimitive::deserialize(deserializer)?);
+        Option::from(Self::new((*scalar).into()))
             .ok_or_else(|| de::Error::custom("expected non-zero scalar"))
     }
 }
 
+/// Adapter which allows sampling with the infallible [`Field::random`] while
+/// capturing the first error returned by [`RngCore::try_fill_bytes`].
+///
+/// Once `rng` has failed, all further output is zero.
+struct TryRng<'a, R: RngCore> {
+    rng: &'a mut R,
+    error: Option<rand_core::Error>,
+}
+
+impl<R: RngCore> RngCore for TryRng<'_, R> {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if self.error.is_some() {
+            dest.fill(0);
+        } else if let Err(error) = self.rng.try_fill_bytes(dest) {
+            dest.fill(0);
+            self.error = Some(error);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
 #[cfg(all(test, feature = "dev"))]
 mod tests {
-    use crate::dev::{NonZeroScalar, Scalar};
+    use crate::dev::{FieldBytes, MockCurve, NonZeroScalar, Scalar, ScalarPrimitive, ScriptedRng};
+    use crate::{bigint::U256, Curve};
     use ff::{Field, PrimeField};
     use hex_literal::hex;
+    use rand_core::OsRng;
     use zeroize::Zeroize;
 
+    #[test]
+    fn random_rejects_zero() {
+        let scalar = NonZeroScalar::random(&mut ScriptedRng::new(32, 0x11));
+        assert_eq!(scalar.to_repr(), FieldBytes::from([0x11; 32]));
+    }
+
+    #[test]
+    fn try_random_matches_random() {
+        for byte in [0x01, 0x11, 0x7f] {
+            let mut rng = ScriptedRng::new(32, byte);
+            let expected = NonZeroScalar::random(&mut rng.clone());
+            assert_eq!(
+                NonZeroScalar::try_random(&mut rng)
+                    .expect("RNG failure")
+                    .to_repr(),
+                expected.to_repr()
+            );
+        }
+    }
+
+    #[test]
+    fn try_random_propagates_error() {
+        // The zero sample is rejected, and then the RNG fails
+        let mut rng = ScriptedRng::failing(32, 0x11);
+        assert!(NonZeroScalar::try_random(&mut rng).is_err());
+        assert_eq!(
+            NonZeroScalar::try_random(&mut rng)
+                .expect("RNG failure")
+                .to_repr(),
+            FieldBytes::from([0x11; 32])
+        );
+    }
+
     #[test]
     fn round_trip() {
         let bytes = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
@@ -393,6 +628,191 @@ mod tests {
         assert_eq!(&bytes, scalar.to_repr().as_slice());
     }
 
+    #[test]
+    fn invert() {
+        for _ in 0..8 {
+            let x = NonZeroScalar::random(&mut OsRng);
+            assert_eq!(*x.invert().invert(), *x);
+            assert_eq!(*x * *x.invert(), Scalar::ONE);
+            assert_eq!(*x.invert_vartime(), *x.invert());
+        }
+    }
+
+    #[test]
+    fn from_uint() {
+        let n = MockCurve::ORDER;
+        let n_minus_one = n.wrapping_sub(&U256::ONE);
+
+        assert!(bool::from(NonZeroScalar::from_uint(U256::ZERO).is_none()));
+        assert!(bool::from(NonZeroScalar::from_uint(n).is_none()));
+        assert_eq!(*NonZeroScalar::from_uint(U256::ONE).unwrap(), Scalar::ONE);
+        assert_eq!(
+            *NonZeroScalar::from_uint(n_minus_one).unwrap(),
+            -Scalar::ONE
+        );
+    }
+
+    #[test]
+    fn from_uint_reduced() {
+        let n = MockCurve::ORDER;
+        let n_minus_one = n.wrapping_sub(&U256::ONE);
+
+        assert!(bool::from(
+            NonZeroScalar::from_uint_reduced(U256::ZERO).is_none()
+        ));
+        assert!(bool::from(NonZeroScalar::from_uint_reduced(n).is_none()));
+        assert_eq!(
+            *NonZeroScalar::from_uint_reduced(U256::ONE).unwrap(),
+            Scalar::ONE
+        );
+        assert_eq!(
+            *NonZeroScalar::from_uint_reduced(n_minus_one).unwrap(),
+            -Scalar::ONE
+        );
+        assert_eq!(
+            *NonZeroScalar::from_uint_reduced(n.wrapping_add(&U256::ONE)).unwrap(),
+            Scalar::ONE
+        );
+    }
+
+    #[test]
+    fn scalar_primitive_conversions() {
+        let scalar = NonZeroScalar::random(&mut OsRng);
+        let primitive = ScalarPrimitive::from(&scalar);
+        assert_eq!(ScalarPrimitive::from(scalar), primitive);
+
+        let converted = NonZeroScalar::try_from(primitive).map(|s| s.to_repr());
+        assert_eq!(converted, Ok(scalar.to_repr()));
+        let converted = NonZeroScalar::try_from(&primitive).map(|s| s.to_repr());
+        assert_eq!(converted, Ok(scalar.to_repr()));
+
+        assert!(NonZeroScalar::try_from(ScalarPrimitive::ZERO).is_err());
+        assert!(NonZeroScalar::try_from(&ScalarPrimitive::ZERO).is_err());
+    }
+
+    #[test]
+    fn reduce_wide() {
+        use crate::{bigint::U512, ops::Reduce};
+
+        let n = MockCurve::ORDER;
+        for uint in [U512::ZERO, n.concat(&U256::ZERO), U256::ZERO.concat(&n)] {
+            let scalar = NonZeroScalar::reduce(uint);
+            assert!(!bool::from(scalar.is_zero()));
+        }
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let bytes = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let scalar = NonZeroScalar::try_from(&bytes[..]).expect("valid scalar");
+        assert_eq!(FieldBytes::from(scalar), bytes);
+        assert_eq!(FieldBytes::from(&scalar), bytes);
+    }
+
+    #[test]
+    fn try_from_slice_rejects_invalid() {
+        let bytes = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let order = hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+
+        assert!(NonZeroScalar::try_from(&bytes[..31]).is_err());
+        assert!(NonZeroScalar::try_from(&[1; 33][..]).is_err());
+        assert!(NonZeroScalar::try_from(&[][..]).is_err());
+        assert!(NonZeroScalar::try_from(&[0; 32][..]).is_err());
+        assert!(NonZeroScalar::try_from(&order[..]).is_err());
+    }
+
+    #[test]
+    fn from_wide_bytes() {
+        // Expected value computed independently with Python's built-in
+        // arbitrary-precision integers.
+        let bytes = hex!(
+            "8a4f1e2bd7c3590e6b20f4a17d9c3e58b1f2063d4a7e95c8d12b3f6e0a9c47d5"
+            "1b7e3c92f0a6d48e25c1b97f3e0d6a84c29f51b3e7a0d86f24c9b15e3a7d0f68"
+        );
+        assert_eq!(
+            NonZeroScalar::from_wide_bytes(&bytes.into()).to_repr(),
+            hex!("ef22642cc5ba870d96b2c21457c424353968158c1dc6e24d33c2e5847b2f6e22")
+        );
+
+        // Multiples of the order reduce to zero, which maps to one.
+        let n_shifted = hex!(
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+            "0000000000000000000000000000000000000000000000000000000000000000"
+        );
+        assert_eq!(
+            *NonZeroScalar::from_wide_bytes(&n_shifted.into()),
+            Scalar::ONE
+        );
+        assert_eq!(
+            *NonZeroScalar::from_wide_bytes(&Default::default()),
+            Scalar::ONE
+        );
+    }
+
+    #[test]
+    fn new_unchecked() {
+        let scalar = Scalar::random(&mut OsRng);
+        assert_eq!(*NonZeroScalar::new_unchecked(scalar), scalar);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "scalar is zero")]
+    fn new_unchecked_zero() {
+        NonZeroScalar::new_unchecked(Scalar::ZERO);
+    }
+
+    #[test]
+    fn mul() {
+        for _ in 0..64 {
+            let a = NonZeroScalar::random(&mut OsRng);
+            let b = NonZeroScalar::random(&mut OsRng);
+            let product = a * b;
+            assert_eq!(*product, *a * *b);
+
+            let mut c = a;
+            c *= b;
+            assert_eq!(*c, *product);
+            c = a;
+            c *= &b;
+            assert_eq!(*c, *product);
+        }
+    }
+
+    #[test]
+    fn neg() {
+        for _ in 0..64 {
+            let a = NonZeroScalar::random(&mut OsRng);
+            assert_eq!(*-a, -*a);
+            assert_eq!(*-&a, -*a);
+            assert_eq!(*-(-a), *a);
+        }
+    }
+
+    #[test]
+    fn from_str_round_trip() {
+        let scalar = NonZeroScalar::random(&mut OsRng);
+
+        for hex in [
+            format!("{scalar}"),
+            format!("{scalar:x}"),
+            format!("{scalar:#X}"),
+        ] {
+            let parsed = hex.parse::<NonZeroScalar>().map(|s| s.to_repr());
+            assert_eq!(parsed, Ok(scalar.to_repr()));
+        }
+    }
+
+    #[test]
+    fn from_str_rejects_invalid() {
+        let zero = "0000000000000000000000000000000000000000000000000000000000000000";
+        let order = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+
+        for hex in [zero, order, "c9afa9d8", ""] {
+            assert!(hex.parse::<NonZeroScalar>().is_err());
+        }
+    }
+
     #[test]
     fn zeroize() {
         let mut scalar = NonZeroScalar::new(Scalar::from(42u64)).unwrap();
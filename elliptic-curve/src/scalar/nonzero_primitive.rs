@@ -0,0 +1,299 @@
+//! Non-zero scalar primitive type.
+
+use super::ScalarPrimitive;
+use crate::{Curve, Error, FieldBytes, Result};
+use core::ops::Deref;
+use rand_core::CryptoRngCore;
+use subtle::{Choice, ConstantTimeEq, CtOption};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(feature = "arithmetic")]
+use crate::{CurveArithmetic, NonZeroScalar};
+
+/// Non-zero [`ScalarPrimitive`].
+///
+/// This is the [`ScalarPrimitive`] equivalent of [`NonZeroScalar`], and
+/// is available without the `arithmetic` feature. It is useful for
+/// validating key material, i.e. checking that a value is in the range
+/// `1..n` where `n` is the order of the curve, without depending on a
+/// curve arithmetic implementation.
+///
+/// The value is zeroized on drop.
+///
+/// [`NonZeroScalar`]: crate::NonZeroScalar
+#[derive(Clone)]
+pub struct NonZeroScalarPrimitive<C: Curve> {
+    scalar: ScalarPrimitive<C>,
+}
+
+impl<C> NonZeroScalarPrimitive<C>
+where
+    C: Curve,
+{
+    /// Generate a random [`NonZeroScalarPrimitive`].
+    ///
+    /// See [`ScalarPrimitive::random_nonzero`] for more information.
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        Self {
+            scalar: ScalarPrimitive::random_nonzero(rng),
+        }
+    }
+
+    /// Generate a random [`NonZeroScalarPrimitive`], returning an error if
+    /// `rng` fails.
+    ///
+    /// See [`ScalarPrimitive::try_random_nonzero`] for more information.
+    pub fn try_random(
+        rng: &mut impl CryptoRngCore,
+    ) -> core::result::Result<Self, rand_core::Error> {
+        ScalarPrimitive::try_random_nonzero(rng).map(|scalar| Self { scalar })
+    }
+
+    /// Create a [`NonZeroScalarPrimitive`] from a [`ScalarPrimitive`].
+    pub fn new(scalar: ScalarPrimitive<C>) -> CtOption<Self> {
+        CtOption::new(Self { scalar }, !scalar.is_zero())
+    }
+
+    /// Create a [`NonZeroScalarPrimitive`] from a `C::Uint`.
+    pub fn from_uint(uint: C::Uint) -> CtOption<Self> {
+        ScalarPrimitive::new(uint).and_then(Self::new)
+    }
+
+    /// Decode a [`NonZeroScalarPrimitive`] from a big endian-serialized field
+    /// element.
+    pub fn from_bytes(bytes: &FieldBytes<C>) -> CtOption<Self> {
+        ScalarPrimitive::from_bytes(bytes).and_then(Self::new)
+    }
+
+    /// Decode a [`NonZeroScalarPrimitive`] from a big endian-serialized field
+    /// element passed as a byte slice.
+    pub fn from_slice(slice: &[u8]) -> Result<Self> {
+        let scalar = ScalarPrimitive::from_slice(slice)?;
+        Option::from(Self::new(scalar)).ok_or(Error)
+    }
+
+    /// Borrow the inner [`ScalarPrimitive`].
+    pub fn as_scalar_primitive(&self) -> &ScalarPrimitive<C> {
+        &self.scalar
+    }
+}
+
+impl<C> AsRef<ScalarPrimitive<C>> for NonZeroScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn as_ref(&self) -> &ScalarPrimitive<C> {
+        &self.scalar
+    }
+}
+
+impl<C> ConstantTimeEq for NonZeroScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.scalar.ct_eq(&other.scalar)
+    }
+}
+
+impl<C> Deref for NonZeroScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Target = ScalarPrimitive<C>;
+
+    fn deref(&self) -> &ScalarPrimitive<C> {
+        &self.scalar
+    }
+}
+
+impl<C: Curve> Eq for NonZeroScalarPrimitive<C> {}
+
+impl<C> PartialEq for NonZeroScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C> From<NonZeroScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn from(scalar: NonZeroScalarPrimitive<C>) -> ScalarPrimitive<C> {
+        scalar.scalar
+    }
+}
+
+impl<C> From<&NonZeroScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn from(scalar: &NonZeroScalarPrimitive<C>) -> ScalarPrimitive<C> {
+        scalar.scalar
+    }
+}
+
+impl<C> From<NonZeroScalarPrimitive<C>> for FieldBytes<C>
+where
+    C: Curve,
+{
+    fn from(scalar: NonZeroScalarPrimitive<C>) -> FieldBytes<C> {
+        scalar.to_bytes()
+    }
+}
+
+impl<C> From<&NonZeroScalarPrimitive<C>> for FieldBytes<C>
+where
+    C: Curve,
+{
+    fn from(scalar: &NonZeroScalarPrimitive<C>) -> FieldBytes<C> {
+        scalar.to_bytes()
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> From<NonZeroScalar<C>> for NonZeroScalarPrimitive<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(scalar: NonZeroScalar<C>) -> NonZeroScalarPrimitive<C> {
+        Self::from(&scalar)
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> From<&NonZeroScalar<C>> for NonZeroScalarPrimitive<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(scalar: &NonZeroScalar<C>) -> NonZeroScalarPrimitive<C> {
+        Self {
+            scalar: scalar.into(),
+        }
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> From<NonZeroScalarPrimitive<C>> for NonZeroScalar<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(scalar: NonZeroScalarPrimitive<C>) -> NonZeroScalar<C> {
+        Self::from(&scalar)
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> From<&NonZeroScalarPrimitive<C>> for NonZeroScalar<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(scalar: &NonZeroScalarPrimitive<C>) -> NonZeroScalar<C> {
+        let scalar = NonZeroScalar::new(scalar.scalar.into());
+        debug_assert!(bool::from(scalar.is_some()));
+        scalar.expect("scalar is non-zero")
+    }
+}
+
+impl<C> Zeroize for NonZeroScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn zeroize(&mut self) {
+        // Use zeroize's volatile writes to ensure value is cleared.
+        self.scalar.zeroize();
+
+        // Write a 1 instead of a 0 to ensure this type's non-zero invariant
+        // is upheld.
+        self.scalar = ScalarPrimitive::ONE;
+    }
+}
+
+impl<C> Drop for NonZeroScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C> ZeroizeOnDrop for NonZeroScalarPrimitive<C> where C: Curve {}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use crate::dev::{MockCurve, NonZeroScalar, NonZeroScalarPrimitive, ScalarPrimitive};
+    use crate::{bigint::U256, Curve};
+    use hex_literal::hex;
+    use rand_core::OsRng;
+    use zeroize::Zeroize;
+
+    #[test]
+    fn round_trip() {
+        let bytes = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let scalar = NonZeroScalarPrimitive::from_bytes(&bytes.into()).unwrap();
+        assert_eq!(&bytes, scalar.to_bytes().as_slice());
+        assert!(NonZeroScalarPrimitive::from_slice(&bytes) == Ok(scalar));
+    }
+
+    #[test]
+    fn rejects_zero() {
+        assert!(bool::from(
+            NonZeroScalarPrimitive::new(ScalarPrimitive::ZERO).is_none()
+        ));
+        assert!(bool::from(
+            NonZeroScalarPrimitive::from_bytes(&Default::default()).is_none()
+        ));
+        assert!(NonZeroScalarPrimitive::from_slice(&[0; 32]).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_slice() {
+        assert!(NonZeroScalarPrimitive::from_slice(&hex!(
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+        ))
+        .is_err());
+        assert!(NonZeroScalarPrimitive::from_slice(&[1; 31]).is_err());
+        assert!(NonZeroScalarPrimitive::from_slice(&[1; 33]).is_err());
+    }
+
+    #[test]
+    fn from_uint() {
+        let n = MockCurve::ORDER;
+        let n_minus_one = n.wrapping_sub(&U256::ONE);
+
+        assert!(bool::from(
+            NonZeroScalarPrimitive::from_uint(U256::ZERO).is_none()
+        ));
+        assert!(bool::from(NonZeroScalarPrimitive::from_uint(n).is_none()));
+        assert_eq!(
+            *NonZeroScalarPrimitive::from_uint(U256::ONE).unwrap(),
+            ScalarPrimitive::ONE
+        );
+        assert_eq!(
+            *NonZeroScalarPrimitive::from_uint(n_minus_one).unwrap(),
+            -ScalarPrimitive::ONE
+        );
+    }
+
+    #[test]
+    fn nonzero_scalar_conversions() {
+        let scalar = NonZeroScalar::random(&mut OsRng);
+        let primitive = NonZeroScalarPrimitive::from(&scalar);
+        assert_eq!(
+            ScalarPrimitive::from(&primitive),
+            ScalarPrimitive::from(&scalar)
+        );
+        assert_eq!(*NonZeroScalar::from(primitive), *scalar);
+    }
+
+    #[test]
+    fn zeroize() {
+        let mut scalar = NonZeroScalarPrimitive::random(&mut OsRng);
+        scalar.zeroize();
+        assert_eq!(*scalar, ScalarPrimitive::ONE);
+    }
+}
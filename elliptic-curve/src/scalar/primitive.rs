@@ -1,32 +1,45 @@
 //! Generic scalar type with primitive functionality.
 
 use crate::{
-    array::Array,
-    bigint::{prelude::*, Limb, NonZero},
-    scalar::FromUintUnchecked,
-    scalar::IsHigh,
-    Curve, Error, FieldBytes, FieldBytesEncoding, Result,
+    array::{
+        typenum::{self, Unsigned},
+        Array, ArraySize,
+    },
+    bigint::{prelude::*, Limb, NonZero, Odd},
+    scalar::{FromUint, FromUintUnchecked, IsHigh, ScalarPrimitiveMontgomery},
+    Curve, Error, FieldBytes, FieldBytesEncoding, FieldBytesSize, Result, WideBytes,
 };
 use base16ct::HexDisplay;
 use core::{
     cmp::Ordering,
     fmt,
-    ops::{Add, AddAssign, Neg, ShrAssign, Sub, SubAssign},
+    hash::{Hash, Hasher},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Shr, ShrAssign, Sub, SubAssign},
     str,
 };
 use rand_core::CryptoRngCore;
 use subtle::{
-    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
-    CtOption,
+    Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater,
+    ConstantTimeLess, CtOption,
 };
-use zeroize::DefaultIsZeroes;
+use zeroize::{DefaultIsZeroes, Zeroize, Zeroizing};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
 
 #[cfg(feature = "arithmetic")]
-use super::{CurveArithmetic, Scalar};
+use {
+    super::{CurveArithmetic, Scalar},
+    crate::ops::{Reduce, ReduceNonZero},
+};
 
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
 
+/// Montgomery parameters for a curve's order.
+type MontyParams<C> = <<<C as Curve>::Uint as Integer>::Monty as Monty>::Params;
+
 /// Generic scalar type with primitive functionality.
 ///
 /// This type provides a baseline level of scalar arithmetic functionality
@@ -40,8 +53,22 @@ use serdect::serde::{de, ser, Deserialize, Serialize};
 ///
 /// The serialization is a fixed-width big endian encoding. When used with
 /// textual formats, the binary data is encoded as hexadecimal.
+///
+/// # Formatting
+///
+/// The [`Debug`][`fmt::Debug`], [`Display`][`fmt::Display`],
+/// [`LowerHex`][`fmt::LowerHex`] and [`UpperHex`][`fmt::UpperHex`] impls
+/// all print the fixed-width big endian encoding as hexadecimal. The hex
+/// impls honor the `#` flag to add a `0x` prefix.
+///
+/// # Zeroization
+///
+/// This type is [`Copy`], so it can't wipe itself on drop. Secret values
+/// such as nonces should be wrapped in [`Zeroizing`], e.g. by using
+/// [`ScalarPrimitive::random_zeroizing`] and
+/// [`ScalarPrimitive::to_bytes_zeroizing`].
 // TODO(tarcieri): use `crypto-bigint`'s `Residue` type, expose more functionality?
-#[derive(Copy, Clone, Debug, Default)]
+#[derive(Copy, Clone, Default)]
 pub struct ScalarPrimitive<C: Curve> {
     /// Inner unsigned integer type.
     inner: C::Uint,
@@ -64,18 +91,184 @@ where
     /// Scalar modulus.
     pub const MODULUS: C::Uint = C::ORDER;
 
+    /// Number of `u64` words used by [`ScalarPrimitive::to_le_u64_words`]
+    /// and [`ScalarPrimitive::from_le_u64_words`].
+    ///
+    /// This is derived from the size of a serialized field element rather
+    /// than `C::Uint`, so it is the same on all platforms.
+    pub const LE_U64_WORDS: usize = C::FieldBytesSize::USIZE.div_ceil(8);
+
     /// Generate a random [`ScalarPrimitive`].
+    ///
+    /// Uses rejection sampling, so the result is exactly uniform. The number
+    /// of values drawn from `rng` varies, but rejected samples are discarded
+    /// without revealing anything about the value which is returned.
     pub fn random(rng: &mut impl CryptoRngCore) -> Self {
         Self {
             inner: C::Uint::random_mod(rng, &NonZero::new(Self::MODULUS).unwrap()),
         }
     }
 
+    /// Generate a random non-zero [`ScalarPrimitive`].
+    ///
+    /// Uses rejection sampling to eliminate zero values, so the result is
+    /// uniform over `[1, n)`. The number of iterations isn't constant-time, but
+    /// only reveals how many discarded samples were zero.
+    pub fn random_nonzero(rng: &mut impl CryptoRngCore) -> Self {
+        loop {
+            let scalar = Self::random(rng);
+
+            if !bool::from(scalar.is_zero()) {
+                break scalar;
+            }
+        }
+    }
+
+    /// Generate a random [`ScalarPrimitive`], returning an error if `rng`
+    /// fails rather than panicking.
+    ///
+    /// Uses rejection sampling like [`ScalarPrimitive::random`], drawing
+    /// `FieldBytesSize` bytes via [`RngCore::try_fill_bytes`] per sample and
+    /// masking off the bits above the order's bit length. The first error
+    /// returned by `rng` is propagated immediately.
+    ///
+    /// [`RngCore::try_fill_bytes`]: rand_core::RngCore::try_fill_bytes
+    pub fn try_random(
+        rng: &mut impl CryptoRngCore,
+    ) -> core::result::Result<Self, rand_core::Error> {
+        let modulus = Self::MODULUS.encode_field_bytes();
+        let top = modulus.iter().position(|&byte| byte != 0).unwrap_or(0);
+        let mask = u8::MAX >> modulus[top].leading_zeros();
+        let mut bytes = Zeroizing::new(FieldBytes::<C>::default());
+
+        loop {
+            rng.try_fill_bytes(&mut bytes)?;
+            bytes[..top].fill(0);
+            bytes[top] &= mask;
+
+            if let Some(scalar) = Option::from(Self::from_bytes(&bytes)) {
+                return Ok(scalar);
+            }
+        }
+    }
+
+    /// Generate a random non-zero [`ScalarPrimitive`], returning an error if
+    /// `rng` fails rather than panicking.
+    ///
+    /// See [`ScalarPrimitive::try_random`] and
+    /// [`ScalarPrimitive::random_nonzero`] for more information.
+    pub fn try_random_nonzero(
+        rng: &mut impl CryptoRngCore,
+    ) -> core::result::Result<Self, rand_core::Error> {
+        loop {
+            let scalar = Self::try_random(rng)?;
+
+            if !bool::from(scalar.is_zero()) {
+                return Ok(scalar);
+            }
+        }
+    }
+
+    /// Generate a random [`ScalarPrimitive`] which is zeroized on drop.
+    ///
+    /// See [`ScalarPrimitive::random`] for more information.
+    pub fn random_zeroizing(rng: &mut impl CryptoRngCore) -> Zeroizing<Self> {
+        Zeroizing::new(Self::random(rng))
+    }
+
+    /// Generate a random [`ScalarPrimitive`] by reducing twice the size of a
+    /// serialized field element's worth of random bytes modulo the curve's
+    /// order.
+    ///
+    /// Always draws exactly `2 * FieldBytesSize` bytes from `rng`, which are
+    /// interpreted as a big endian integer. The statistical distance of the
+    /// result from uniform is at most `n / 2^(16 * FieldBytesSize)`, i.e.
+    /// negligible, which matches the wide reduction used by RFC 9380
+    /// `hash_to_field` and many other implementations.
+    pub fn random_reduced(rng: &mut impl CryptoRngCore) -> Self {
+        let mut hi = FieldBytes::<C>::default();
+        let mut lo = FieldBytes::<C>::default();
+        rng.fill_bytes(&mut hi);
+        rng.fill_bytes(&mut lo);
+
+        let mut hi_uint = C::Uint::decode_field_bytes(&hi);
+        let mut lo_uint = C::Uint::decode_field_bytes(&lo);
+        hi.zeroize();
+        lo.zeroize();
+
+        let ret = Self::from_uint_reduced(hi_uint).mul_by_pow2(8 * C::FieldBytesSize::USIZE)
+            + Self::from_uint_reduced(lo_uint);
+
+        hi_uint.zeroize();
+        lo_uint.zeroize();
+        ret
+    }
+
     /// Create a new scalar from [`Curve::Uint`].
+    ///
+    /// Returns none if `uint` is not less than the curve's order. Use this
+    /// when the encoding must be canonical, e.g. when parsing signatures.
     pub fn new(uint: C::Uint) -> CtOption<Self> {
         CtOption::new(Self { inner: uint }, uint.ct_lt(&Self::MODULUS))
     }
 
+    /// Create a new scalar from [`Curve::Uint`] without checking that it's
+    /// less than the curve's order.
+    ///
+    /// Unlike [`ScalarPrimitive::new`] this is a `const fn`, so it can be used
+    /// to define constants from `const fn` constructors of the underlying
+    /// integer type, e.g. `crypto_bigint::Uint::from_be_hex`. Generic trait
+    /// methods can't be called in a `const` context, so the range check can't
+    /// be performed at compile time: callers must ensure `uint` is less than
+    /// the curve's order, e.g. by checking the constant with
+    /// [`ScalarPrimitive::new`] in a test.
+    ///
+    /// Since it can construct invalid scalars, this is only available with
+    /// the `hazmat` feature enabled.
+    #[cfg(feature = "hazmat")]
+    pub const fn new_unchecked(uint: C::Uint) -> Self {
+        Self { inner: uint }
+    }
+
+    /// Create a new scalar from [`Curve::Uint`], reducing it modulo the
+    /// curve's order in constant time.
+    ///
+    /// Always succeeds. Use this when reduction is desired, e.g. when deriving
+    /// nonces from counters or truncated hashes, and [`ScalarPrimitive::new`]
+    /// when non-canonical values must be rejected.
+    pub fn from_uint_reduced(uint: C::Uint) -> Self {
+        Self {
+            inner: uint % NonZero::new(Self::MODULUS).unwrap(),
+        }
+    }
+
+    /// Widen a `u64` operand of a mixed-width operation to [`Curve::Uint`].
+    ///
+    /// It's only reduced if the curve's order is at most 64 bits, which only
+    /// depends on the (public) order, so the operation is a single modular
+    /// operation for all other curves.
+    fn u64_operand(n: u64) -> C::Uint {
+        let n = C::Uint::from(n);
+
+        if Self::MODULUS.bits_vartime() <= 64 {
+            n % NonZero::new(Self::MODULUS).unwrap()
+        } else {
+            n
+        }
+    }
+
+    /// Create a new non-zero scalar from [`Curve::Uint`] by reducing it modulo
+    /// `n - 1` and adding one, where `n` is the curve's order.
+    ///
+    /// Always succeeds, runs in constant time, and never returns zero, even
+    /// when `uint` is zero or a multiple of `n`.
+    pub fn from_uint_reduced_nonzero(uint: C::Uint) -> Self {
+        let n_minus_one = NonZero::new(Self::MODULUS.wrapping_sub(&C::Uint::ONE)).unwrap();
+        Self {
+            inner: (uint % n_minus_one).wrapping_add(&C::Uint::ONE),
+        }
+    }
+
     /// Decode [`ScalarPrimitive`] from a serialized field element
     pub fn from_bytes(bytes: &FieldBytes<C>) -> CtOption<Self> {
         Self::new(C::Uint::decode_field_bytes(bytes))
@@ -87,6 +280,212 @@ where
         Option::from(Self::from_bytes(&bytes)).ok_or(Error)
     }
 
+    /// Decode [`ScalarPrimitive`] from a big endian byte slice which may be
+    /// shorter than a serialized field element, left-padding it with zeros.
+    ///
+    /// An empty slice decodes as zero. Returns an error if the slice is longer
+    /// than a serialized field element or encodes a value which is not less
+    /// than the curve's order.
+    pub fn from_be_slice_padded(slice: &[u8]) -> Result<Self> {
+        let mut bytes = FieldBytes::<C>::default();
+        let offset = bytes.len().checked_sub(slice.len()).ok_or(Error)?;
+        bytes[offset..].copy_from_slice(slice);
+        let ret = Option::from(Self::from_bytes(&bytes)).ok_or(Error);
+        bytes.zeroize();
+        ret
+    }
+
+    /// Decode [`ScalarPrimitive`] from a big endian byte string of any length,
+    /// reducing it modulo the curve's order.
+    ///
+    /// When given an input at least twice the size of a field element (e.g.
+    /// the output of RFC 9380 `expand_message` or HKDF), the bias of the
+    /// result is negligible.
+    ///
+    /// Constant-time with respect to the contents of `bytes`, but not its
+    /// length.
+    pub fn from_be_bytes_reduced(bytes: &[u8]) -> Self {
+        let (head, tail) = bytes.split_at(bytes.len() % C::Uint::BYTES);
+        let radix = Self::monty_radix(&Self::monty_params());
+
+        tail.chunks_exact(C::Uint::BYTES)
+            .fold(Self::from_be_chunk_reduced(head), |acc, chunk| {
+                acc * radix + Self::from_be_chunk_reduced(chunk)
+            })
+    }
+
+    /// Decode [`ScalarPrimitive`] from a little endian byte string of any
+    /// length, reducing it modulo the curve's order.
+    ///
+    /// See [`ScalarPrimitive::from_be_bytes_reduced`] for more information.
+    pub fn from_le_bytes_reduced(bytes: &[u8]) -> Self {
+        let (body, top) = bytes.split_at(bytes.len() - bytes.len() % C::Uint::BYTES);
+        let radix = Self::monty_radix(&Self::monty_params());
+
+        body.rchunks_exact(C::Uint::BYTES)
+            .fold(Self::from_le_chunk_reduced(top), |acc, chunk| {
+                acc * radix + Self::from_le_chunk_reduced(chunk)
+            })
+    }
+
+    /// Decode [`ScalarPrimitive`] from a big endian integer twice the size of
+    /// a serialized field element, reducing it modulo the curve's order.
+    ///
+    /// The bias of the result is negligible. See
+    /// [`ScalarPrimitive::from_be_bytes_reduced`] for more information.
+    pub fn from_be_bytes_wide_reduced(bytes: &WideBytes<C>) -> Self
+    where
+        typenum::Sum<FieldBytesSize<C>, FieldBytesSize<C>>: ArraySize,
+    {
+        Self::from_be_bytes_reduced(bytes)
+    }
+
+    /// Decode [`ScalarPrimitive`] from a little endian integer twice the size
+    /// of a serialized field element, reducing it modulo the curve's order.
+    ///
+    /// See [`ScalarPrimitive::from_be_bytes_wide_reduced`] for more
+    /// information.
+    pub fn from_le_bytes_wide_reduced(bytes: &WideBytes<C>) -> Self
+    where
+        typenum::Sum<FieldBytesSize<C>, FieldBytesSize<C>>: ArraySize,
+    {
+        Self::from_le_bytes_reduced(bytes)
+    }
+
+    /// Decode at most `C::Uint::BYTES` big endian bytes, reducing the result.
+    fn from_be_chunk_reduced(chunk: &[u8]) -> Self {
+        let mut repr = C::Uint::ZERO.to_be_bytes();
+        let offset = C::Uint::BYTES - chunk.len();
+        repr.as_mut()[offset..].copy_from_slice(chunk);
+        let uint = C::Uint::from_be_bytes(repr);
+        repr.as_mut().zeroize();
+        Self::from_uint_reduced(uint)
+    }
+
+    /// Decode at most `C::Uint::BYTES` little endian bytes, reducing the result.
+    fn from_le_chunk_reduced(chunk: &[u8]) -> Self {
+        let mut repr = C::Uint::ZERO.to_le_bytes();
+        repr.as_mut()[..chunk.len()].copy_from_slice(chunk);
+        let uint = C::Uint::from_le_bytes(repr);
+        repr.as_mut().zeroize();
+        Self::from_uint_reduced(uint)
+    }
+
+    /// `2^C::Uint::BITS mod n`, i.e. the Montgomery radix.
+    fn monty_radix(params: &MontyParams<C>) -> Self {
+        Self {
+            inner: *<C::Uint as Integer>::Monty::one(params.clone()).as_montgomery(),
+        }
+    }
+
+    /// Montgomery parameters for the curve's order.
+    ///
+    /// These can't be computed at compile time for a generic [`Curve::Uint`],
+    /// so callers which need them more than once should compute them once
+    /// and pass them by reference.
+    fn monty_params() -> MontyParams<C> {
+        let modulus = Odd::new(Self::MODULUS).expect("curve order should be odd");
+        <C::Uint as Integer>::Monty::new_params_vartime(modulus)
+    }
+
+    /// Decode [`ScalarPrimitive`] from little endian `u64` words.
+    ///
+    /// The input must be exactly [`ScalarPrimitive::LE_U64_WORDS`] in length
+    /// and encode a value less than the curve's order.
+    pub fn from_le_u64_words(words: &[u64]) -> Result<Self> {
+        if words.len() != Self::LE_U64_WORDS {
+            return Err(Error);
+        }
+
+        let mut bytes = FieldBytes::<C>::default();
+        let len = bytes.len();
+
+        for (i, word) in words.iter().enumerate() {
+            for (j, byte) in word.to_le_bytes().into_iter().enumerate() {
+                match len.checked_sub(8 * i + j + 1) {
+                    Some(pos) => bytes[pos] = byte,
+                    None if byte == 0 => (),
+                    None => return Err(Error),
+                }
+            }
+        }
+
+        Self::from_slice(&bytes)
+    }
+
+    /// Encode [`ScalarPrimitive`] as little endian `u64` words.
+    ///
+    /// The output must be exactly [`ScalarPrimitive::LE_U64_WORDS`] in length.
+    pub fn to_le_u64_words(&self, words: &mut [u64]) -> Result<()> {
+        if words.len() != Self::LE_U64_WORDS {
+            return Err(Error);
+        }
+
+        words.fill(0);
+
+        for (i, byte) in self.to_bytes().iter().rev().enumerate() {
+            words[i / 8] |= u64::from(*byte) << (8 * (i % 8));
+        }
+
+        Ok(())
+    }
+
+    /// Parse a [`ScalarPrimitive`] from a string of digits in the given radix,
+    /// e.g. 10 for decimal test vectors.
+    ///
+    /// Leading zeros and an optional `+` sign are accepted. Returns an error if
+    /// `radix` is not in the range `2..=36`, the string is empty, contains an
+    /// invalid digit, or encodes a value which is not less than the curve's
+    /// order.
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self> {
+        let digits = src.strip_prefix('+').unwrap_or(src);
+        if digits.is_empty() || !(2..=36).contains(&radix) {
+            return Err(Error);
+        }
+
+        let radix_uint = C::Uint::from(radix);
+        let mut uint = C::Uint::ZERO;
+
+        for c in digits.chars() {
+            let digit = C::Uint::from(c.to_digit(radix).ok_or(Error)?);
+            uint = Option::from(
+                uint.checked_mul(&radix_uint)
+                    .and_then(|uint| uint.checked_add(&digit)),
+            )
+            .ok_or(Error)?;
+        }
+
+        Option::from(Self::new(uint)).ok_or(Error)
+    }
+
+    /// Format this [`ScalarPrimitive`] as a string of lowercase digits in the
+    /// given radix, without leading zeros.
+    ///
+    /// # Panics
+    ///
+    /// If `radix` is not in the range `2..=36`.
+    #[cfg(feature = "alloc")]
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+
+        let divisor = NonZero::new(Limb::from(radix)).unwrap();
+        let mut uint = self.inner;
+        let mut digits = Vec::new();
+
+        loop {
+            let (quotient, rem) = uint.div_rem_limb(divisor);
+            let rem = u32::try_from(rem.0).expect("remainder should be less than radix");
+            digits.push(char::from_digit(rem, radix).expect("digit should be in range"));
+            uint = quotient;
+
+            if bool::from(uint.is_zero()) {
+                break;
+            }
+        }
+
+        digits.iter().rev().collect()
+    }
+
     /// Borrow the inner `C::Uint`.
     pub fn as_uint(&self) -> &C::Uint {
         &self.inner
@@ -97,6 +496,426 @@ where
         self.inner.as_ref()
     }
 
+    /// Compute the multiplicative inverse of this scalar modulo the curve's
+    /// order in constant time.
+    ///
+    /// Returns none if the scalar is zero.
+    pub fn invert(&self) -> CtOption<Self> {
+        self.inner
+            .inv_mod(&Self::MODULUS)
+            .map(|inner| Self { inner })
+    }
+
+    /// Invert every scalar in `scalars` in place using Montgomery's trick,
+    /// which costs a single inversion plus a linear number of multiplications.
+    /// The multiplications are performed in Montgomery form, since a single
+    /// generic modular multiplication is a sizable fraction of an inversion.
+    ///
+    /// Zero elements have no inverse and are left unchanged, while all other
+    /// elements are still inverted. Returns a [`Choice`] which is truthy if
+    /// every element was inverted, and falsy if any element was zero.
+    #[cfg(feature = "alloc")]
+    pub fn batch_invert(scalars: &mut [Self]) -> Choice
+    where
+        <C::Uint as Integer>::Monty: Zeroize,
+    {
+        if scalars.is_empty() {
+            return Choice::from(1);
+        }
+
+        let params = Self::monty_params();
+
+        // Zero elements are replaced by one, so they don't affect the product
+        let mut elems: Vec<_> = scalars
+            .iter()
+            .map(|scalar| {
+                let elem = Self::conditional_select(scalar, &Self::ONE, scalar.is_zero());
+                <C::Uint as Integer>::Monty::new(elem.inner, params.clone())
+            })
+            .collect();
+
+        // `products[i]` is the product of `elems[..=i]`
+        let mut products = Vec::with_capacity(elems.len());
+        let mut acc = elems[0].clone();
+        products.push(acc.clone());
+
+        for elem in &elems[1..] {
+            acc *= elem;
+            products.push(acc.clone());
+        }
+
+        // Inverting the Montgomery representation `acc * R` of `acc` gives
+        // `acc^-1 * R^-1`, from which multiplying by `acc` gives `R^-1` and
+        // multiplying by `R` gives `acc^-1`.
+        let radix = *<C::Uint as Integer>::Monty::one(params.clone()).as_montgomery();
+        let radix = <C::Uint as Integer>::Monty::new(radix, params.clone());
+        let mut acc_inv = acc
+            .as_montgomery()
+            .inv_mod(&Self::MODULUS)
+            .map(|inv| <C::Uint as Integer>::Monty::new(inv, params))
+            .expect("product of non-zero scalars should be invertible");
+        let mut radix_inv = acc_inv.clone() * &acc;
+        let mut inv = acc_inv.clone() * radix;
+
+        let mut ret = Choice::from(1);
+
+        for i in (0..scalars.len()).rev() {
+            let mut scalar_inv = match i {
+                0 => inv.clone(),
+                _ => inv.clone() * &products[i - 1],
+            };
+            inv *= &elems[i];
+
+            // Multiplying by `R^-1` converts out of Montgomery form
+            scalar_inv *= &radix_inv;
+
+            let is_zero = scalars[i].is_zero();
+            let scalar_inv = Self {
+                inner: *scalar_inv.as_montgomery(),
+            };
+            scalars[i] = Self::conditional_select(&scalar_inv, &scalars[i], is_zero);
+            ret &= !is_zero;
+        }
+
+        elems.zeroize();
+        products.zeroize();
+        acc.zeroize();
+        acc_inv.zeroize();
+        radix_inv.zeroize();
+        inv.zeroize();
+        ret
+    }
+
+    /// Compute the greatest common divisor of this scalar and `other` in
+    /// constant time.
+    ///
+    /// Unlike [`bigint::Gcd`], neither argument is required to be odd. By convention
+    /// `gcd(0, 0)` is zero.
+    pub fn gcd(&self, other: &C::Uint) -> C::Uint {
+        let mut a = self.inner;
+        let mut b = *other;
+        let mut shift = 0u32;
+
+        // Strip the power of two common to both arguments so at least one is odd
+        for _ in 0..C::Uint::BITS {
+            let both_even = a.is_even() & b.is_even();
+            a = C::Uint::conditional_select(&a, &(a >> 1u32), both_even);
+            b = C::Uint::conditional_select(&b, &(b >> 1u32), both_even);
+            shift += u32::from(both_even.unwrap_u8());
+        }
+
+        let a_is_odd = a.is_odd();
+        let odd = C::Uint::conditional_select(&b, &a, a_is_odd);
+        let rest = C::Uint::conditional_select(&a, &b, a_is_odd);
+        let mut ret = odd.gcd(&rest).unwrap_or(C::Uint::ZERO);
+
+        // Restore the common power of two without branching on its value
+        for i in 0..C::Uint::BITS {
+            ret = C::Uint::conditional_select(&ret, &(ret << 1u32), shift.ct_gt(&i));
+        }
+
+        ret
+    }
+
+    /// Is this scalar coprime to the curve's order?
+    ///
+    /// For curves of prime order this is equivalent to the scalar being
+    /// non-zero.
+    pub fn is_coprime_to_order(&self) -> Choice {
+        Self::MODULUS
+            .gcd(&self.inner)
+            .map(|gcd| gcd.ct_eq(&C::Uint::ONE))
+            .unwrap_or(Choice::from(0))
+    }
+
+    /// Compute the linear combination `a * b + c * d` modulo the curve's order
+    /// in constant time.
+    ///
+    /// This is the shape of many signature equations, e.g. `s = r + c * x`.
+    /// Both products are computed with widening multiplication and summed
+    /// before a single wide reduction.
+    pub fn lincomb(a: &Self, b: &Self, c: &Self, d: &Self) -> Self
+    where
+        C::Uint: Concat + for<'a> WideningMul<&'a C::Uint, Output = WideUint<C>>,
+        WideUint<C>: Encoding + Split<Output = C::Uint> + Zeroize,
+    {
+        let mut ab = a.inner.widening_mul(&b.inner);
+        let mut cd = c.inner.widening_mul(&d.inner);
+        let mut sum = ab.wrapping_add(&cd);
+
+        // A carry out of the sum is worth `2^(2 * C::Uint::BITS) mod n`,
+        // which is the Montgomery representation of the Montgomery radix
+        let carry = sum.ct_lt(&ab);
+        let params = Self::monty_params();
+        let radix = Self::monty_radix(&params);
+        let radix_squared = Self {
+            inner: *<C::Uint as Integer>::Monty::new(radix.inner, params.clone()).as_montgomery(),
+        };
+        let ret = Self::reduce_wide(sum.clone(), &params)
+            + Self::conditional_select(&Self::ZERO, &radix_squared, carry);
+
+        ab.zeroize();
+        cd.zeroize();
+        sum.zeroize();
+        ret
+    }
+
+    /// Compute `self / rhs`, i.e. `self * rhs^-1` modulo the curve's order, in
+    /// constant time.
+    ///
+    /// Returns none if `rhs` is zero.
+    pub fn checked_div(&self, rhs: &Self) -> CtOption<Self> {
+        rhs.invert().map(|rhs_inv| *self * rhs_inv)
+    }
+
+    /// Compute a square root of this scalar modulo the curve's order.
+    ///
+    /// Uses the `(n + 1) / 4` exponentiation when `n ≡ 3 (mod 4)`, and a
+    /// constant-time variant of Tonelli-Shanks otherwise. Returns none if
+    /// the scalar is not a quadratic residue.
+    ///
+    /// The curve's order must be prime.
+    pub fn sqrt(&self) -> CtOption<Self> {
+        let params = Self::monty_params();
+        let n_minus_one = Self::MODULUS.wrapping_sub(&C::Uint::ONE);
+        let s = n_minus_one.trailing_zeros_vartime();
+
+        let root = if s == 1 {
+            // n ≡ 3 (mod 4): the root is self^((n + 1) / 4)
+            let exp = (Self::MODULUS >> 2u32).wrapping_add(&C::Uint::ONE);
+            self.pow_bounded_exp(&exp, exp.bits_vartime(), &params)
+        } else {
+            self.sqrt_tonelli_shanks(s, &(n_minus_one >> s), &params)
+        };
+
+        CtOption::new(root, (root * root).ct_eq(self))
+    }
+
+    /// Constant-time Tonelli-Shanks, where `n - 1 = 2^s * t` with `t` odd.
+    ///
+    /// Adapted from `ff::helpers::sqrt_tonelli_shanks`. The result must be
+    /// checked by the caller, as it is garbage if `self` is a non-residue.
+    fn sqrt_tonelli_shanks(&self, s: u32, t: &C::Uint, params: &MontyParams<C>) -> Self {
+        let pow_vartime =
+            |base: &Self, exp: &C::Uint| base.pow_bounded_exp(exp, exp.bits_vartime(), params);
+
+        // Find a quadratic non-residue using Euler's criterion. This only
+        // depends on the (public) modulus, so it can be variable-time.
+        let n_minus_one = -Self::ONE;
+        let mut z = Self::from(2u64);
+        while pow_vartime(&z, &(n_minus_one.inner >> 1u32)) != n_minus_one {
+            z += Self::ONE;
+        }
+
+        // w = self^((t - 1) / 2)
+        let w = pow_vartime(self, &(*t >> 1u32));
+        let mut v = s;
+        let mut x = w * self;
+        let mut b = x * w;
+
+        // Primitive 2^s-th root of unity
+        let mut z = pow_vartime(&z, t);
+
+        for max_v in (1..=s).rev() {
+            let mut k = 1;
+            let mut b2k = b * b;
+            let mut j_less_than_v = Choice::from(1);
+
+            for j in 2..max_v {
+                let b2k_is_one = b2k.ct_eq(&Self::ONE);
+                let squared = Self::conditional_select(&b2k, &z, b2k_is_one);
+                let squared = squared * squared;
+                b2k = Self::conditional_select(&squared, &b2k, b2k_is_one);
+                let new_z = Self::conditional_select(&z, &squared, b2k_is_one);
+                j_less_than_v &= !j.ct_eq(&v);
+                k = u32::conditional_select(&j, &k, b2k_is_one);
+                z = Self::conditional_select(&z, &new_z, j_less_than_v);
+            }
+
+            let result = x * z;
+            x = Self::conditional_select(&result, &x, b.ct_eq(&Self::ONE));
+            z *= z;
+            b *= z;
+            v = k;
+        }
+
+        x
+    }
+
+    /// Double this scalar modulo the curve's order.
+    pub fn double(&self) -> Self {
+        Self {
+            inner: self.inner.add_mod(&self.inner, &Self::MODULUS),
+        }
+    }
+
+    /// Multiply this scalar by `2^k` modulo the curve's order.
+    ///
+    /// Constant-time with respect to the value of this scalar, but not `k`.
+    pub fn mul_by_pow2(&self, k: usize) -> Self {
+        let k = C::Uint::from(k as u64);
+        *self * Self::from(2u8).pow_vartime(&k)
+    }
+
+    /// Negate this scalar if it's greater than n / 2 (see [`IsHigh`]),
+    /// in constant time.
+    ///
+    /// This is useful for e.g. ECDSA "low S" normalization.
+    pub fn normalize_low(&self) -> Self {
+        let mut result = *self;
+        result.conditional_negate(self.is_high());
+        result
+    }
+
+    /// Halve this scalar modulo the curve's order, i.e. compute `x` such
+    /// that `x + x == self`.
+    ///
+    /// Odd values have the (odd) modulus added before the division.
+    pub fn div2_mod(&self) -> Self {
+        let half = self.inner >> 1u32;
+        let half_plus_modulus = half
+            .wrapping_add(&(Self::MODULUS >> 1u32))
+            .wrapping_add(&C::Uint::ONE);
+
+        Self {
+            inner: C::Uint::conditional_select(&half, &half_plus_modulus, self.is_odd()),
+        }
+    }
+
+    /// Raise this scalar to the given power modulo the curve's order.
+    ///
+    /// Uses fixed-window exponentiation with constant-time table lookups,
+    /// and is constant-time with respect to both the base and the exponent.
+    pub fn pow(&self, exp: &C::Uint) -> Self {
+        self.pow_bounded_exp(exp, C::Uint::BITS, &Self::monty_params())
+    }
+
+    /// Raise this scalar to the given power modulo the curve's order, in
+    /// variable time with respect to the exponent.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This method should only be used with public exponents, as its
+    /// running time depends on the exponent's bit length.
+    pub fn pow_vartime(&self, exp: &C::Uint) -> Self {
+        self.pow_bounded_exp(exp, exp.bits_vartime(), &Self::monty_params())
+    }
+
+    /// Raise this scalar to the given power, considering only the lowest
+    /// `exp_bits` bits of the exponent.
+    ///
+    /// Only `exp_bits` may leak through timing.
+    fn pow_bounded_exp(&self, exp: &C::Uint, exp_bits: u32, params: &MontyParams<C>) -> Self {
+        let base = <C::Uint as Integer>::Monty::new(self.inner, params.clone());
+        Self::from_monty(&base.pow_bounded_exp(exp, exp_bits))
+    }
+
+    /// Convert this scalar into Montgomery form.
+    ///
+    /// Each conversion computes the Montgomery parameters for the curve's
+    /// order, which the result then carries along, so scalars used in
+    /// repeated multiplications should be converted once and kept in
+    /// Montgomery form. See [`ScalarPrimitiveMontgomery`] for more information.
+    pub fn to_montgomery(&self) -> ScalarPrimitiveMontgomery<C>
+    where
+        <C::Uint as Integer>::Monty: Zeroize,
+    {
+        ScalarPrimitiveMontgomery::from_monty(<C::Uint as Integer>::Monty::new(
+            self.inner,
+            Self::monty_params(),
+        ))
+    }
+
+    /// Convert a scalar out of Montgomery form.
+    ///
+    /// This is relatively expensive since the generic Montgomery form API
+    /// requires an inversion to compute `R^-1`.
+    pub fn from_montgomery(scalar: &ScalarPrimitiveMontgomery<C>) -> Self
+    where
+        <C::Uint as Integer>::Monty: Zeroize,
+    {
+        Self::from_monty(scalar.as_monty())
+    }
+
+    /// Convert out of Montgomery form by multiplying by `R^-1`, the value
+    /// whose Montgomery representation is 1.
+    fn from_monty(value: &<C::Uint as Integer>::Monty) -> Self {
+        let params = value.params().clone();
+        let r = <C::Uint as Integer>::Monty::one(params.clone());
+        let r_inv = r.as_montgomery().inv_mod(&Self::MODULUS).unwrap();
+        let r_inv = <C::Uint as Integer>::Monty::new(r_inv, params);
+
+        Self {
+            inner: *(value.clone() * r_inv).as_montgomery(),
+        }
+    }
+
+    /// Compute the non-adjacent form (NAF) of this scalar.
+    ///
+    /// Equivalent to [`ScalarPrimitive::to_wnaf_vartime`] with `w = 2`.
+    ///
+    /// <div class="warning">
+    /// This is a variable-time operation and should only be used with public
+    /// scalars, e.g. when verifying signatures.
+    /// </div>
+    pub fn to_naf_vartime(&self) -> impl Iterator<Item = i8> {
+        self.to_wnaf_vartime(2)
+    }
+
+    /// Compute the width-`w` non-adjacent form (wNAF) of this scalar.
+    ///
+    /// Returns signed digits starting from the least significant, such that
+    /// the sum of `digit * 2^i` is equal to the scalar. Every non-zero digit is
+    /// odd, lies in `(-2^(w-1), 2^(w-1))`, and is followed by at least `w - 1`
+    /// zero digits. Exactly `n.bits() + 1` digits are produced.
+    ///
+    /// <div class="warning">
+    /// This is a variable-time operation and should only be used with public
+    /// scalars, e.g. when verifying signatures.
+    /// </div>
+    ///
+    /// # Panics
+    ///
+    /// If `w` is not in the range `2..=8`.
+    pub fn to_wnaf_vartime(&self, w: usize) -> impl Iterator<Item = i8> {
+        assert!((2..=8).contains(&w), "window size must be in 2..=8");
+
+        let uint = self.inner;
+        let bit = move |i| u32::try_from(i).is_ok_and(|i| i < C::Uint::BITS && uint.bit_vartime(i));
+        let len = Self::MODULUS.bits_vartime() as usize + 1;
+        super::wnaf_vartime(bit, len, w)
+    }
+
+    /// Get the bit at position `index`, where bit 0 is the least significant.
+    ///
+    /// Constant-time with respect to the value of the scalar. Returns a falsy
+    /// [`Choice`] if `index` is out of range.
+    pub fn bit(&self, index: usize) -> Choice {
+        u32::try_from(index).map_or(Choice::from(0), |index| self.inner.bit(index))
+    }
+
+    /// Get the bit at position `index`, where bit 0 is the least significant.
+    ///
+    /// Returns `false` if `index` is out of range.
+    ///
+    /// <div class="warning">
+    /// This is a variable-time operation and should only be used with public
+    /// scalars.
+    /// </div>
+    pub fn bit_vartime(&self, index: usize) -> bool {
+        u32::try_from(index).is_ok_and(|index| self.inner.bit_vartime(index))
+    }
+
+    /// Iterate over the bits of this scalar in constant time, from least to
+    /// most significant.
+    ///
+    /// Always yields exactly `C::Uint::BITS` bits. The iterator can be reversed
+    /// to visit the most significant bits first.
+    pub fn bits(&self) -> impl DoubleEndedIterator<Item = Choice> + ExactSizeIterator {
+        let inner = self.inner;
+        (0..C::Uint::BITS).map(move |index| inner.bit(index))
+    }
+
     /// Is this [`ScalarPrimitive`] value equal to zero?
     pub fn is_zero(&self) -> Choice {
         self.inner.is_zero()
@@ -117,6 +936,27 @@ where
         self.inner.encode_field_bytes()
     }
 
+    /// Encode [`ScalarPrimitive`] as a serialized field element, along with the
+    /// length of its minimal big endian encoding without leading zeros.
+    ///
+    /// The minimal encoding is the last `len` bytes of the returned array. Zero
+    /// is encoded as a single zero byte, as in DER.
+    ///
+    /// The length isn't computed in constant time, since it's inherently
+    /// revealed by the encoding.
+    pub fn to_be_bytes_minimal(&self) -> (FieldBytes<C>, usize) {
+        let bytes = self.to_bytes();
+        let leading_zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+        let len = (bytes.len() - leading_zeros).max(1);
+        (bytes, len)
+    }
+
+    /// Encode [`ScalarPrimitive`] as a serialized field element which is
+    /// zeroized on drop.
+    pub fn to_bytes_zeroizing(&self) -> Zeroizing<FieldBytes<C>> {
+        Zeroizing::new(self.to_bytes())
+    }
+
     /// Convert to a `C::Uint`.
     pub fn to_uint(&self) -> C::Uint {
         self.inner
@@ -134,13 +974,29 @@ where
     }
 }
 
+impl<C> FromUint for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn from_uint(uint: C::Uint) -> CtOption<Self> {
+        Self::new(uint)
+    }
+}
+
 #[cfg(feature = "arithmetic")]
 impl<C> ScalarPrimitive<C>
 where
     C: CurveArithmetic,
 {
     /// Convert [`ScalarPrimitive`] into a given curve's scalar type.
-    pub(super) fn to_scalar(self) -> Scalar<C> {
+    ///
+    /// This conversion is infallible since both types are always fully
+    /// reduced modulo the curve's order, and doesn't round trip through a
+    /// serialized field element.
+    ///
+    /// The opposite conversion is provided by the `Into<ScalarPrimitive<C>>`
+    /// bound on [`CurveArithmetic::Scalar`].
+    pub fn to_scalar(self) -> Scalar<C> {
         Scalar::<C>::from_uint_unchecked(self.inner)
     }
 }
@@ -206,6 +1062,21 @@ where
     }
 }
 
+/// Hashes the big endian encoding of the scalar, so the result doesn't depend
+/// on the limb size.
+///
+/// <div class="warning">
+/// Hashing is not constant-time and must not be used with secret scalars.
+/// </div>
+impl<C> Hash for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(&self.to_bytes());
+    }
+}
+
 impl<C> PartialOrd for ScalarPrimitive<C>
 where
     C: Curve,
@@ -224,6 +1095,39 @@ where
     }
 }
 
+impl<C> From<u8> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn from(n: u8) -> Self {
+        Self {
+            inner: C::Uint::from(n),
+        }
+    }
+}
+
+impl<C> From<u16> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn from(n: u16) -> Self {
+        Self {
+            inner: C::Uint::from(n),
+        }
+    }
+}
+
+impl<C> From<u32> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn from(n: u32) -> Self {
+        Self {
+            inner: C::Uint::from(n),
+        }
+    }
+}
+
 impl<C> From<u64> for ScalarPrimitive<C>
 where
     C: Curve,
@@ -235,6 +1139,23 @@ where
     }
 }
 
+/// The value is reduced modulo the curve's order, so this works for any size
+/// of `C::Uint`, including 64-bit integers.
+impl<C> From<u128> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    #[allow(clippy::cast_possible_truncation)]
+    fn from(n: u128) -> Self {
+        let hi = Self::from_uint_reduced(C::Uint::from((n >> 64) as u64));
+        let lo = Self::from_uint_reduced(C::Uint::from(n as u64));
+
+        // `2^64 mod n`, computed without a shift which may overflow `C::Uint`
+        let two_pow_64 = Self::from_uint_reduced(C::Uint::from(u64::MAX)) + Self::ONE;
+        hi * two_pow_64 + lo
+    }
+}
+
 impl<C> Add<ScalarPrimitive<C>> for ScalarPrimitive<C>
 where
     C: Curve,
@@ -319,36 +1240,346 @@ where
     }
 }
 
-impl<C> Neg for ScalarPrimitive<C>
+/// # Panics
+///
+/// If the curve's order is even, since modular multiplication is only
+/// implemented for odd moduli.
+impl<C> Mul<ScalarPrimitive<C>> for ScalarPrimitive<C>
 where
     C: Curve,
 {
     type Output = Self;
 
-    fn neg(self) -> Self {
-        Self {
-            inner: self.inner.neg_mod(&Self::MODULUS),
-        }
+    fn mul(self, other: Self) -> Self {
+        self.mul(&other)
     }
 }
 
-impl<C> Neg for &ScalarPrimitive<C>
+/// # Panics
+///
+/// If the curve's order is even, since modular multiplication is only
+/// implemented for odd moduli.
+impl<C> Mul<&ScalarPrimitive<C>> for ScalarPrimitive<C>
 where
     C: Curve,
 {
-    type Output = ScalarPrimitive<C>;
+    type Output = Self;
+
+    fn mul(self, other: &Self) -> Self {
+        Self {
+            inner: self.inner.mul_mod(&other.inner, &Self::MODULUS),
+        }
+    }
+}
+
+impl<C> MulAssign<ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<C> MulAssign<&ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn mul_assign(&mut self, other: &Self) {
+        *self = *self * other;
+    }
+}
+
+impl<C> Add<u64> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = Self;
+
+    fn add(self, other: u64) -> Self {
+        Self {
+            inner: self
+                .inner
+                .add_mod(&Self::u64_operand(other), &Self::MODULUS),
+        }
+    }
+}
+
+impl<C> AddAssign<u64> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn add_assign(&mut self, other: u64) {
+        *self = *self + other;
+    }
+}
+
+impl<C> Sub<u64> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = Self;
+
+    fn sub(self, other: u64) -> Self {
+        Self {
+            inner: self
+                .inner
+                .sub_mod(&Self::u64_operand(other), &Self::MODULUS),
+        }
+    }
+}
+
+impl<C> SubAssign<u64> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn sub_assign(&mut self, other: u64) {
+        *self = *self - other;
+    }
+}
+
+impl<C> Mul<u64> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = Self;
+
+    fn mul(self, other: u64) -> Self {
+        Self {
+            inner: self
+                .inner
+                .mul_mod(&Self::u64_operand(other), &Self::MODULUS),
+        }
+    }
+}
+
+impl<C> MulAssign<u64> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn mul_assign(&mut self, other: u64) {
+        *self = *self * other;
+    }
+}
+
+/// # Panics
+///
+/// If the divisor is zero. Use [`ScalarPrimitive::checked_div`] to handle
+/// this case.
+impl<C> Div<ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.div(&other)
+    }
+}
+
+/// # Panics
+///
+/// If the divisor is zero. Use [`ScalarPrimitive::checked_div`] to handle
+/// this case.
+impl<C> Div<&ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = Self;
+
+    fn div(self, other: &Self) -> Self {
+        self.checked_div(other).expect("division by zero")
+    }
+}
+
+/// # Panics
+///
+/// If the divisor is zero.
+impl<C> DivAssign<ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+/// # Panics
+///
+/// If the divisor is zero.
+impl<C> DivAssign<&ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn div_assign(&mut self, other: &Self) {
+        *self = *self / other;
+    }
+}
+
+impl<C> From<ScalarPrimitive<C>> for FieldBytes<C>
+where
+    C: Curve,
+{
+    fn from(scalar: ScalarPrimitive<C>) -> Self {
+        Self::from(&scalar)
+    }
+}
+
+impl<C> From<&ScalarPrimitive<C>> for FieldBytes<C>
+where
+    C: Curve,
+{
+    fn from(scalar: &ScalarPrimitive<C>) -> Self {
+        scalar.to_bytes()
+    }
+}
+
+impl<C> TryFrom<FieldBytes<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Error = Error;
+
+    fn try_from(bytes: FieldBytes<C>) -> Result<Self> {
+        Self::try_from(&bytes)
+    }
+}
+
+impl<C> TryFrom<&FieldBytes<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &FieldBytes<C>) -> Result<Self> {
+        Option::from(Self::from_bytes(bytes)).ok_or(Error)
+    }
+}
+
+/// Decode a big endian serialized scalar, which must be exactly the size of
+/// a field element and less than the curve's order.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "dev")]
+/// # {
+/// use elliptic_curve::dev::ScalarPrimitive;
+/// use hex_literal::hex;
+///
+/// let bytes = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+/// let scalar = ScalarPrimitive::try_from(&bytes[..]).expect("valid scalar");
+/// assert_eq!(scalar.to_bytes().as_slice(), &bytes);
+///
+/// // Inputs which aren't the same size as a field element are rejected
+/// assert!(ScalarPrimitive::try_from(&bytes[1..]).is_err());
+/// # }
+/// ```
+impl<C> TryFrom<&[u8]> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Error = Error;
+
+    fn try_from(slice: &[u8]) -> Result<Self> {
+        Self::from_slice(slice)
+    }
+}
+
+impl<C> Sum for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a, C> Sum<&'a ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn sum<I: Iterator<Item = &'a ScalarPrimitive<C>>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<C> Product for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl<'a, C> Product<&'a ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn product<I: Iterator<Item = &'a ScalarPrimitive<C>>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<C> Neg for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            inner: self.inner.neg_mod(&Self::MODULUS),
+        }
+    }
+}
+
+impl<C> Neg for &ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = ScalarPrimitive<C>;
 
     fn neg(self) -> ScalarPrimitive<C> {
         -*self
     }
 }
 
+/// Plain (i.e. non-modular) right shift of the inner integer.
+///
+/// Shifting by the bit size of `C::Uint` or more results in zero.
+impl<C> Shr<usize> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = Self;
+
+    fn shr(mut self, rhs: usize) -> Self {
+        self >>= rhs;
+        self
+    }
+}
+
+impl<C> Shr<usize> for &ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = ScalarPrimitive<C>;
+
+    fn shr(self, rhs: usize) -> ScalarPrimitive<C> {
+        *self >> rhs
+    }
+}
+
 impl<C> ShrAssign<usize> for ScalarPrimitive<C>
 where
     C: Curve,
 {
     fn shr_assign(&mut self, rhs: usize) {
-        self.inner >>= rhs;
+        self.inner = u32::try_from(rhs)
+            .map(|shift| self.inner.wrapping_shr(shift))
+            .unwrap_or(C::Uint::ZERO);
     }
 }
 
@@ -362,70 +1593,1354 @@ where
     }
 }
 
-impl<C> fmt::Display for ScalarPrimitive<C>
+/// Double-width integer type used for wide reductions.
+type WideUint<C> = <<C as Curve>::Uint as Concat>::Output;
+
+impl<C> ScalarPrimitive<C>
 where
     C: Curve,
+    C::Uint: Concat,
+    WideUint<C>: Split<Output = C::Uint>,
 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{self:X}")
+    /// Reduce a wide integer by computing `hi * R + lo`, where
+    /// `n = hi * 2^C::Uint::BITS + lo` and `R` is the Montgomery radix.
+    ///
+    /// `hi * R` is the Montgomery representation of `hi`, so it's computed by
+    /// a conversion rather than a full modular multiplication.
+    fn reduce_wide(n: WideUint<C>, params: &MontyParams<C>) -> Self {
+        let (mut lo, mut hi) = n.split();
+        let mut hi_r = Self::from_uint_reduced(hi);
+        hi_r.inner = *<C::Uint as Integer>::Monty::new(hi_r.inner, params.clone()).as_montgomery();
+        let ret = hi_r + Self::from_uint_reduced(lo);
+        lo.zeroize();
+        hi.zeroize();
+        hi_r.zeroize();
+        ret
     }
 }
 
-impl<C> fmt::LowerHex for ScalarPrimitive<C>
+/// Reduce an integer twice the width of [`Curve::Uint`] modulo the curve's
+/// order in constant time.
+///
+/// [`Reduce::reduce_bytes`] interprets its input as a big endian integer.
+#[cfg(feature = "arithmetic")]
+impl<C> Reduce<WideUint<C>> for ScalarPrimitive<C>
 where
     C: Curve,
+    C::Uint: Concat,
+    WideUint<C>: Encoding + Split<Output = C::Uint>,
 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:x}", HexDisplay(&self.to_bytes()))
+    type Bytes = <WideUint<C> as Encoding>::Repr;
+
+    fn reduce(n: WideUint<C>) -> Self {
+        Self::reduce_wide(n, &Self::monty_params())
+    }
+
+    fn reduce_bytes(bytes: &Self::Bytes) -> Self {
+        Self::from_be_bytes_reduced(bytes.as_ref())
     }
 }
 
-impl<C> fmt::UpperHex for ScalarPrimitive<C>
+/// Reduce an integer twice the width of [`Curve::Uint`] modulo `n - 1` and
+/// add one, where `n` is the curve's order, in constant time.
+///
+/// See [`ScalarPrimitive::from_uint_reduced_nonzero`] for more information.
+#[cfg(feature = "arithmetic")]
+impl<C> ReduceNonZero<WideUint<C>> for ScalarPrimitive<C>
 where
     C: Curve,
+    C::Uint: Concat,
+    WideUint<C>: Encoding + Split<Output = C::Uint>,
 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:X}", HexDisplay(&self.to_bytes()))
+    fn reduce_nonzero(n: WideUint<C>) -> Self {
+        let n_minus_one = Self::MODULUS.wrapping_sub(&C::Uint::ONE);
+        let modulus = NonZero::new(n_minus_one.concat(&C::Uint::ZERO)).unwrap();
+        let (mut lo, _) = (n % modulus).split();
+        let ret = Self {
+            inner: lo.wrapping_add(&C::Uint::ONE),
+        };
+        lo.zeroize();
+        ret
+    }
+
+    fn reduce_nonzero_bytes(bytes: &Self::Bytes) -> Self {
+        Self::reduce_nonzero(WideUint::<C>::from_be_bytes(*bytes))
     }
 }
 
-impl<C> str::FromStr for ScalarPrimitive<C>
+#[cfg(feature = "num-traits")]
+impl<C> num_traits::Zero for ScalarPrimitive<C>
 where
     C: Curve,
 {
-    type Err = Error;
+    fn zero() -> Self {
+        Self::ZERO
+    }
 
-    fn from_str(hex: &str) -> Result<Self> {
-        let mut bytes = FieldBytes::<C>::default();
-        base16ct::lower::decode(hex, &mut bytes)?;
-        Self::from_slice(&bytes)
+    fn is_zero(&self) -> bool {
+        Self::is_zero(self).into()
     }
 }
 
-#[cfg(feature = "serde")]
-impl<C> Serialize for ScalarPrimitive<C>
+#[cfg(feature = "num-traits")]
+impl<C> num_traits::One for ScalarPrimitive<C>
 where
     C: Curve,
 {
-    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
-    where
-        S: ser::Serializer,
-    {
-        serdect::array::serialize_hex_upper_or_bin(&self.to_bytes(), serializer)
+    fn one() -> Self {
+        Self::ONE
     }
 }
 
-#[cfg(feature = "serde")]
-impl<'de, C> Deserialize<'de> for ScalarPrimitive<C>
+impl<C> fmt::Debug for ScalarPrimitive<C>
 where
     C: Curve,
 {
-    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        let mut bytes = FieldBytes::<C>::default();
-        serdect::array::deserialize_hex_or_bin(&mut bytes, deserializer)?;
-        Self::from_slice(&bytes).map_err(|_| de::Error::custom("scalar out of range"))
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ScalarPrimitive({self:#X})")
+    }
+}
+
+/// Never prints the scalar, which may be secret (e.g. the scalar wrapped by
+/// a [`SecretKey`][`crate::SecretKey`] or a nonce).
+#[cfg(feature = "defmt")]
+impl<C> defmt::Format for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "ScalarPrimitive {{ .. }}")
+    }
+}
+
+impl<C> fmt::Display for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(self, f)
+    }
+}
+
+impl<C> fmt::LowerHex for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        write!(f, "{:x}", HexDisplay(&self.to_bytes()))
+    }
+}
+
+impl<C> fmt::UpperHex for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        write!(f, "{:X}", HexDisplay(&self.to_bytes()))
+    }
+}
+
+/// Parse a fixed-width big endian hex string, with or without a `0x` prefix.
+///
+/// Upper and lower case hex are both accepted. Parsing is not constant-time.
+impl<C> str::FromStr for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Err = Error;
+
+    fn from_str(hex: &str) -> Result<Self> {
+        let hex = hex
+            .strip_prefix("0x")
+            .or_else(|| hex.strip_prefix("0X"))
+            .unwrap_or(hex);
+
+        let mut bytes = FieldBytes::<C>::default();
+
+        if base16ct::mixed::decode(hex, &mut bytes)?.len() == bytes.len() {
+            Self::from_slice(&bytes)
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C> Serialize for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serdect::array::serialize_hex_upper_or_bin(&self.to_bytes_zeroizing(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C> Deserialize<'de> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let mut bytes = Zeroizing::new(FieldBytes::<C>::default());
+        serdect::array::deserialize_hex_or_bin(&mut bytes, deserializer)?;
+        Self::from_slice(&bytes).map_err(|_| de::Error::custom("scalar out of range"))
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use crate::dev::{FieldBytes, MockCurve, ScalarPrimitive, ScriptedRng};
+    use crate::{
+        bigint::{U256, U64},
+        scalar::IsHigh,
+        Curve, FieldBytesEncoding,
+    };
+    use hex_literal::hex;
+    use rand_core::OsRng;
+    use subtle::{Choice, ConditionallyNegatable};
+    use zeroize::ZeroizeOnDrop;
+
+    #[test]
+    fn fmt_one() {
+        let one = ScalarPrimitive::ONE;
+        let hex = "0000000000000000000000000000000000000000000000000000000000000001";
+        assert_eq!(format!("{one:x}"), hex);
+        assert_eq!(format!("{one:X}"), hex);
+        assert_eq!(format!("{one}"), hex);
+        assert_eq!(format!("{one:#x}"), format!("0x{hex}"));
+        assert_eq!(format!("{one:?}"), format!("ScalarPrimitive(0x{hex})"));
+    }
+
+    #[test]
+    fn fmt_n_minus_one() {
+        let n_minus_one = -ScalarPrimitive::ONE;
+        let hex = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550";
+        assert_eq!(format!("{n_minus_one:x}"), hex);
+        assert_eq!(format!("{n_minus_one:X}"), hex.to_uppercase());
+        assert_eq!(
+            format!("{n_minus_one:#X}"),
+            format!("0x{}", hex.to_uppercase())
+        );
+    }
+
+    #[test]
+    fn from_str_radix() {
+        let n_minus_one = ScalarPrimitive::from_str_radix(
+            "115792089210356248762697446949407573529996955224135760342422259061068512044368",
+            10,
+        );
+        assert_eq!(n_minus_one, Ok(-ScalarPrimitive::ONE));
+
+        assert_eq!(
+            ScalarPrimitive::from_str_radix("+000123", 10),
+            Ok(ScalarPrimitive::from(123u8))
+        );
+        assert_eq!(
+            ScalarPrimitive::from_str_radix("fF", 16),
+            Ok(ScalarPrimitive::from(255u8))
+        );
+        assert_eq!(
+            ScalarPrimitive::from_str_radix("0", 2),
+            Ok(ScalarPrimitive::ZERO)
+        );
+    }
+
+    #[test]
+    fn from_str_radix_rejects_invalid() {
+        let n = "115792089210356248762697446949407573529996955224135760342422259061068512044369";
+        let overflow =
+            "1157920892103562487626974469494075735299969552241357603424222590610685120443690";
+
+        for (src, radix) in [
+            ("", 10),
+            ("+", 10),
+            ("-1", 10),
+            ("12a", 10),
+            ("0x12", 16),
+            ("2", 2),
+            ("1", 1),
+            ("1", 37),
+            (n, 10),
+            (overflow, 10),
+        ] {
+            assert!(ScalarPrimitive::from_str_radix(src, radix).is_err());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_string_radix() {
+        assert_eq!(ScalarPrimitive::ZERO.to_string_radix(10), "0");
+        assert_eq!(ScalarPrimitive::from(255u8).to_string_radix(16), "ff");
+        assert_eq!(ScalarPrimitive::from(5u8).to_string_radix(2), "101");
+        assert_eq!(
+            (-ScalarPrimitive::ONE).to_string_radix(10),
+            "115792089210356248762697446949407573529996955224135760342422259061068512044368"
+        );
+
+        let a = ScalarPrimitive::random(&mut OsRng);
+        for radix in [2, 10, 16, 36] {
+            assert_eq!(
+                ScalarPrimitive::from_str_radix(&a.to_string_radix(radix), radix),
+                Ok(a)
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_round_trip() {
+        for scalar in [
+            ScalarPrimitive::ZERO,
+            ScalarPrimitive::ONE,
+            -ScalarPrimitive::ONE,
+            ScalarPrimitive::random(&mut OsRng),
+        ] {
+            assert_eq!(format!("{scalar:x}").parse::<ScalarPrimitive>(), Ok(scalar));
+            assert_eq!(format!("{scalar:X}").parse::<ScalarPrimitive>(), Ok(scalar));
+            assert_eq!(
+                format!("{scalar:#x}").parse::<ScalarPrimitive>(),
+                Ok(scalar)
+            );
+            assert_eq!(
+                format!("{scalar:#X}").parse::<ScalarPrimitive>(),
+                Ok(scalar)
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_rejects_invalid() {
+        // Equal to the order
+        let n = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+        assert!(n.parse::<ScalarPrimitive>().is_err());
+
+        // Too short and too long
+        assert!("01".parse::<ScalarPrimitive>().is_err());
+        assert!(format!("00{n}").parse::<ScalarPrimitive>().is_err());
+
+        // Not hex
+        let bogus = "zz00000000000000000000000000000000000000000000000000000000000001";
+        assert!(bogus.parse::<ScalarPrimitive>().is_err());
+    }
+
+    #[test]
+    fn from_small_ints() {
+        let sum = ScalarPrimitive::from(5u8) + ScalarPrimitive::from(7u16);
+        assert_eq!(sum, ScalarPrimitive::from(12u64));
+        assert_eq!(ScalarPrimitive::from(12u32), ScalarPrimitive::from(12u64));
+    }
+
+    #[test]
+    fn from_u128() {
+        let n = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        let expected = ScalarPrimitive::from(0x0123_4567_89ab_cdefu64)
+            * ScalarPrimitive::from(1u128 << 64)
+            + ScalarPrimitive::from(0xfedc_ba98_7654_3210u64);
+        assert_eq!(ScalarPrimitive::from(n), expected);
+        assert_eq!(
+            ScalarPrimitive::from(u128::MAX) + ScalarPrimitive::ONE,
+            ScalarPrimitive::from(1u128 << 64) * ScalarPrimitive::from(1u128 << 64)
+        );
+    }
+
+    /// Fake curve with a 64-bit order, the largest prime below `2^64`.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+    struct Curve64;
+
+    impl Curve for Curve64 {
+        type FieldBytesSize = crate::array::typenum::U8;
+        type Uint = U64;
+
+        const ORDER: U64 = U64::from_u64(0xffff_ffff_ffff_ffc5);
+    }
+
+    impl FieldBytesEncoding<Curve64> for U64 {}
+
+    /// Fake curve with a 64-bit order `≡ 3 (mod 4)`, the largest such prime
+    /// below `2^64`.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+    struct Curve64Mod4Is3;
+
+    impl Curve for Curve64Mod4Is3 {
+        type FieldBytesSize = crate::array::typenum::U8;
+        type Uint = U64;
+
+        const ORDER: U64 = U64::from_u64(0xffff_ffff_ffff_ff43);
+    }
+
+    impl FieldBytesEncoding<Curve64Mod4Is3> for U64 {}
+
+    /// Fake curve with an even order, to test boundaries at `n / 2`.
+    ///
+    /// Multiplication panics for even orders, so tests with this curve must
+    /// only use additive operations.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+    struct EvenOrderCurve;
+
+    impl Curve for EvenOrderCurve {
+        type FieldBytesSize = crate::array::typenum::U8;
+        type Uint = U64;
+
+        const ORDER: U64 = U64::from_u64(1000);
+    }
+
+    impl FieldBytesEncoding<EvenOrderCurve> for U64 {}
+
+    #[test]
+    fn from_u128_64_bit_uint() {
+        let order = 0xffff_ffff_ffff_ffc5u128;
+        for n in [0, 1, order - 1, order, u128::from(u64::MAX), u128::MAX] {
+            let expected = u64::try_from(n % order).expect("reduced value fits in u64");
+            let expected = crate::ScalarPrimitive::<Curve64>::new(U64::from(expected));
+            assert_eq!(
+                crate::ScalarPrimitive::<Curve64>::from(n),
+                expected.unwrap(),
+                "n = {n:#x}"
+            );
+        }
+    }
+
+    #[test]
+    fn from_bytes_reduced() {
+        let n = hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+        assert_eq!(
+            ScalarPrimitive::from_be_bytes_reduced(&n),
+            ScalarPrimitive::ZERO
+        );
+
+        let mut n_plus_one = n;
+        n_plus_one[31] += 1;
+        assert_eq!(
+            ScalarPrimitive::from_be_bytes_reduced(&n_plus_one),
+            ScalarPrimitive::ONE
+        );
+
+        // 2^512 - 1
+        assert_eq!(
+            ScalarPrimitive::from_be_bytes_reduced(&[0xff; 64]),
+            ScalarPrimitive::from_bytes(
+                &hex!("66e12d94f3d956202845b2392b6bec594699799c49bd6fa683244c95be79eea1").into()
+            )
+            .unwrap()
+        );
+
+        // 2^256
+        let mut pow2 = [0u8; 33];
+        pow2[0] = 1;
+        assert_eq!(
+            ScalarPrimitive::from_be_bytes_reduced(&pow2),
+            ScalarPrimitive::from_bytes(
+                &hex!("00000000ffffffff00000000000000004319055258e8617b0c46353d039cdaaf").into()
+            )
+            .unwrap()
+        );
+
+        assert_eq!(
+            ScalarPrimitive::from_be_bytes_reduced(&[]),
+            ScalarPrimitive::ZERO
+        );
+        assert_eq!(
+            ScalarPrimitive::from_be_bytes_reduced(&[42]),
+            ScalarPrimitive::from(42u64)
+        );
+    }
+
+    #[test]
+    fn from_bytes_wide_reduced() {
+        // Expected values computed independently with Python's built-in
+        // arbitrary-precision integers.
+        let bytes = hex!(
+            "8a4f1e2bd7c3590e6b20f4a17d9c3e58b1f2063d4a7e95c8d12b3f6e0a9c47d5"
+            "1b7e3c92f0a6d48e25c1b97f3e0d6a84c29f51b3e7a0d86f24c9b15e3a7d0f68"
+        );
+        let expected = ScalarPrimitive::from_bytes(
+            &hex!("ef22642cc5ba870d96b2c21457c424353968158c1dc6e24d33c2e5847b2f6e22").into(),
+        )
+        .unwrap();
+        assert_eq!(
+            ScalarPrimitive::from_be_bytes_wide_reduced(&bytes.into()),
+            expected
+        );
+
+        let mut reversed = bytes;
+        reversed.reverse();
+        assert_eq!(
+            ScalarPrimitive::from_le_bytes_wide_reduced(&reversed.into()),
+            expected
+        );
+    }
+
+    #[test]
+    fn reduce_wide() {
+        use crate::{bigint::U512, ops::Reduce};
+
+        // 2^512 - 1, computed independently with Python's built-in
+        // arbitrary-precision integers.
+        let expected = ScalarPrimitive::from_bytes(
+            &hex!("66e12d94f3d956202845b2392b6bec594699799c49bd6fa683244c95be79eea1").into(),
+        )
+        .unwrap();
+        assert_eq!(ScalarPrimitive::reduce(U512::MAX), expected);
+        assert_eq!(ScalarPrimitive::reduce_bytes(&[0xff; 64]), expected);
+
+        // Multiples of the order reduce to zero.
+        let n = MockCurve::ORDER.concat(&U256::ZERO);
+        assert_eq!(ScalarPrimitive::reduce(n), ScalarPrimitive::ZERO);
+        let n_shifted = U256::ZERO.concat(&MockCurve::ORDER);
+        assert_eq!(ScalarPrimitive::reduce(n_shifted), ScalarPrimitive::ZERO);
+        assert_eq!(
+            ScalarPrimitive::reduce(U256::ONE.concat(&MockCurve::ORDER)),
+            ScalarPrimitive::ONE
+        );
+    }
+
+    #[test]
+    fn from_uint_reduced_nonzero() {
+        let n = MockCurve::ORDER;
+        let n_minus_one = -ScalarPrimitive::ONE;
+
+        for (uint, expected) in [
+            (U256::ZERO, ScalarPrimitive::ONE),
+            (U256::ONE, ScalarPrimitive::from(2u8)),
+            (n_minus_one.to_uint(), ScalarPrimitive::ONE),
+            (n, ScalarPrimitive::from(2u8)),
+            (n.wrapping_sub(&U256::from(2u8)), n_minus_one),
+        ] {
+            assert_eq!(ScalarPrimitive::from_uint_reduced_nonzero(uint), expected);
+        }
+
+        // (2^256 - 1) mod (n - 1) + 1, computed independently with Python's
+        // built-in arbitrary-precision integers.
+        assert_eq!(
+            ScalarPrimitive::from_uint_reduced_nonzero(U256::MAX).to_bytes(),
+            hex!("00000000ffffffff00000000000000004319055258e8617b0c46353d039cdab0")
+        );
+    }
+
+    #[test]
+    fn reduce_nonzero_wide() {
+        use crate::{bigint::U512, ops::ReduceNonZero};
+
+        let n = MockCurve::ORDER;
+        for uint in [
+            U512::ZERO,
+            n.concat(&U256::ZERO),
+            U256::ZERO.concat(&n),
+            n.concat(&n),
+        ] {
+            let scalar = ScalarPrimitive::reduce_nonzero(uint);
+            assert!(!bool::from(scalar.is_zero()));
+        }
+
+        // (2^512 - 1) mod (n - 1) + 1, computed independently with Python's
+        // built-in arbitrary-precision integers.
+        let expected = hex!("66e12d96f3d9561f2845b2382b6bec58cccb844181c03d42909a7f58b0f66550");
+        assert_eq!(
+            ScalarPrimitive::reduce_nonzero(U512::MAX).to_bytes(),
+            expected
+        );
+        assert_eq!(
+            ScalarPrimitive::reduce_nonzero_bytes(&[0xff; 64]).to_bytes(),
+            expected
+        );
+    }
+
+    #[test]
+    fn from_le_bytes_reduced() {
+        let mut bytes = hex!(
+            "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"
+            "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"
+        );
+        let expected = ScalarPrimitive::from_bytes(
+            &hex!("a639b5be93c94b061af3f409b19bf469ce4d0471d7be08ec6b22205b7ee5c62a").into(),
+        )
+        .unwrap();
+        assert_eq!(ScalarPrimitive::from_be_bytes_reduced(&bytes), expected);
+
+        bytes.reverse();
+        assert_eq!(ScalarPrimitive::from_le_bytes_reduced(&bytes), expected);
+
+        // 2^256
+        let mut pow2 = [0u8; 33];
+        pow2[32] = 1;
+        assert_eq!(
+            ScalarPrimitive::from_le_bytes_reduced(&pow2),
+            ScalarPrimitive::from_bytes(
+                &hex!("00000000ffffffff00000000000000004319055258e8617b0c46353d039cdaaf").into()
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn field_bytes_round_trip() {
+        let a = ScalarPrimitive::random(&mut OsRng);
+        let bytes = FieldBytes::from(a);
+        assert_eq!(ScalarPrimitive::try_from(bytes), Ok(a));
+        assert_eq!(ScalarPrimitive::try_from(&bytes[..]), Ok(a));
+    }
+
+    #[test]
+    fn try_from_rejects_invalid() {
+        let n = FieldBytes::from(hex!(
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+        ));
+        assert!(ScalarPrimitive::try_from(n).is_err());
+        assert!(ScalarPrimitive::try_from(&n[..]).is_err());
+        assert!(ScalarPrimitive::try_from(&[0u8; 33][..]).is_err());
+        assert!(ScalarPrimitive::try_from(&[0u8; 31][..]).is_err());
+    }
+
+    #[test]
+    fn random_nonzero() {
+        assert_eq!(
+            ScalarPrimitive::random(&mut ScriptedRng::new(32, 0x11)),
+            ScalarPrimitive::ZERO
+        );
+
+        let scalar = ScalarPrimitive::random_nonzero(&mut ScriptedRng::new(32, 0x11));
+        assert_eq!(scalar.to_bytes(), FieldBytes::from([0x11; 32]));
+
+        for _ in 0..8 {
+            assert!(!bool::from(
+                ScalarPrimitive::random_nonzero(&mut OsRng).is_zero()
+            ));
+        }
+    }
+
+    #[test]
+    fn try_random() {
+        let mut rng = ScriptedRng::failing(0, 0x11);
+        assert!(ScalarPrimitive::try_random(&mut rng).is_err());
+        assert_eq!(
+            ScalarPrimitive::try_random(&mut rng)
+                .expect("RNG failure")
+                .to_bytes(),
+            FieldBytes::from([0x11; 32])
+        );
+
+        let a = ScalarPrimitive::try_random(&mut OsRng).expect("RNG failure");
+        assert!(a.as_uint() < &ScalarPrimitive::MODULUS);
+    }
+
+    #[test]
+    fn try_random_nonzero() {
+        // Zero samples are retried, but the subsequent RNG error is returned
+        let mut rng = ScriptedRng::failing(64, 0x11);
+        assert!(ScalarPrimitive::try_random_nonzero(&mut rng).is_err());
+        assert_eq!(
+            ScalarPrimitive::try_random_nonzero(&mut rng)
+                .expect("RNG failure")
+                .to_bytes(),
+            FieldBytes::from([0x11; 32])
+        );
+    }
+
+    #[test]
+    fn random_reduced() {
+        const SAMPLES: usize = 1024;
+        let mut high_bits = 0;
+        let mut low_bits = 0;
+
+        for _ in 0..SAMPLES {
+            let a = ScalarPrimitive::random_reduced(&mut OsRng);
+            assert!(a.as_uint() < &ScalarPrimitive::MODULUS);
+            high_bits += usize::from(a.as_uint().bit_vartime(255));
+            low_bits += usize::from(a.as_uint().bit_vartime(0));
+        }
+
+        // The order is just below 2^256, so both bits should be set in about half
+        // of all samples. These bounds are more than 6 standard deviations wide.
+        assert!((SAMPLES / 2 - 100..SAMPLES / 2 + 100).contains(&high_bits));
+        assert!((SAMPLES / 2 - 100..SAMPLES / 2 + 100).contains(&low_bits));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_invert() {
+        let mut scalars = [(); 16].map(|_| ScalarPrimitive::random(&mut OsRng));
+        let expected = scalars.map(|s| s.invert().unwrap());
+
+        assert!(bool::from(ScalarPrimitive::batch_invert(&mut scalars)));
+        assert_eq!(scalars, expected);
+        assert!(bool::from(ScalarPrimitive::batch_invert(&mut [])));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_invert_with_zero() {
+        for zero_pos in [0, 3, 7] {
+            let mut scalars = [(); 8].map(|_| ScalarPrimitive::random(&mut OsRng));
+            scalars[zero_pos] = ScalarPrimitive::ZERO;
+            let expected = scalars.map(|s| s.invert().unwrap_or(ScalarPrimitive::ZERO));
+
+            assert!(!bool::from(ScalarPrimitive::batch_invert(&mut scalars)));
+            assert_eq!(scalars, expected);
+        }
+
+        let mut zeros = [ScalarPrimitive::ZERO; 3];
+        assert!(!bool::from(ScalarPrimitive::batch_invert(&mut zeros)));
+        assert_eq!(zeros, [ScalarPrimitive::ZERO; 3]);
+    }
+
+    #[test]
+    fn bit() {
+        let a = ScalarPrimitive::from(0b1011_0001u8);
+
+        for (i, expected) in [1, 0, 0, 0, 1, 1, 0, 1, 0].into_iter().enumerate() {
+            assert_eq!(a.bit(i).unwrap_u8(), expected);
+            assert_eq!(a.bit_vartime(i), expected == 1);
+        }
+
+        let n_minus_one = -ScalarPrimitive::ONE;
+        assert!(!bool::from(n_minus_one.bit(0)));
+        assert!(bool::from(n_minus_one.bit(255)));
+        assert!(!bool::from(n_minus_one.bit(256)));
+        assert!(!bool::from(n_minus_one.bit(usize::MAX)));
+        assert!(n_minus_one.bit_vartime(255));
+        assert!(!n_minus_one.bit_vartime(256));
+        assert!(!n_minus_one.bit_vartime(usize::MAX));
+    }
+
+    #[test]
+    fn bits() {
+        let a = ScalarPrimitive::random(&mut OsRng);
+        assert_eq!(a.bits().len(), 256);
+
+        let recomputed = a.bits().rev().fold(ScalarPrimitive::ZERO, |acc, bit| {
+            acc.double() + ScalarPrimitive::from(bit.unwrap_u8())
+        });
+        assert_eq!(recomputed, a);
+
+        for (i, bit) in a.bits().enumerate() {
+            assert_eq!(bool::from(bit), a.bit_vartime(i));
+        }
+    }
+
+    #[test]
+    fn gcd() {
+        let gcd = |a: u64, b: u64| ScalarPrimitive::from(a).gcd(&U256::from(b));
+
+        assert_eq!(gcd(12, 18), U256::from(6u8));
+        assert_eq!(gcd(18, 12), U256::from(6u8));
+        assert_eq!(gcd(7, 5), U256::ONE);
+        assert_eq!(gcd(48, 64), U256::from(16u8));
+        assert_eq!(gcd(0, 40), U256::from(40u8));
+        assert_eq!(gcd(40, 0), U256::from(40u8));
+        assert_eq!(gcd(0, 0), U256::ZERO);
+        assert_eq!(gcd(1 << 63, 1 << 40), U256::from(1u64 << 40));
+        // n - 1 = ...2550 is a multiple of 16
+        assert_eq!(
+            (-ScalarPrimitive::ONE).gcd(&U256::from(32u8)),
+            U256::from(16u8)
+        );
+    }
+
+    #[test]
+    fn is_coprime_to_order() {
+        assert!(bool::from(ScalarPrimitive::ONE.is_coprime_to_order()));
+        assert!(bool::from((-ScalarPrimitive::ONE).is_coprime_to_order()));
+        assert!(!bool::from(ScalarPrimitive::ZERO.is_coprime_to_order()));
+    }
+
+    #[cfg(feature = "hazmat")]
+    #[test]
+    fn new_unchecked() {
+        const N_MINUS_ONE: ScalarPrimitive = ScalarPrimitive::new_unchecked(U256::from_be_hex(
+            "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550",
+        ));
+
+        assert_eq!(
+            ScalarPrimitive::new(*N_MINUS_ONE.as_uint()).unwrap(),
+            N_MINUS_ONE
+        );
+        assert_eq!(N_MINUS_ONE, -ScalarPrimitive::ONE);
+    }
+
+    #[test]
+    fn from_uint_reduced() {
+        let n = ScalarPrimitive::MODULUS;
+        assert_eq!(ScalarPrimitive::from_uint_reduced(n), ScalarPrimitive::ZERO);
+        assert_eq!(
+            ScalarPrimitive::from_uint_reduced(n.wrapping_add(&U256::from(5u8))),
+            ScalarPrimitive::from(5u8)
+        );
+        assert_eq!(
+            ScalarPrimitive::from_uint_reduced(U256::MAX),
+            ScalarPrimitive::from_uint_reduced(U256::MAX.wrapping_sub(&n))
+        );
+        assert!(bool::from(ScalarPrimitive::new(n).is_none()));
+    }
+
+    #[test]
+    fn zeroizing() {
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>(_: &T) {}
+
+        let a = ScalarPrimitive::random_zeroizing(&mut OsRng);
+        assert_zeroize_on_drop(&a);
+        assert!(a.as_uint() < &ScalarPrimitive::MODULUS);
+
+        let bytes = a.to_bytes_zeroizing();
+        assert_zeroize_on_drop(&bytes);
+        assert_eq!(*bytes, a.to_bytes());
+    }
+
+    #[test]
+    fn to_be_bytes_minimal() {
+        let (bytes, len) = ScalarPrimitive::ZERO.to_be_bytes_minimal();
+        assert_eq!(&bytes[bytes.len() - len..], &[0]);
+
+        let (bytes, len) = ScalarPrimitive::from(0x1234u16).to_be_bytes_minimal();
+        assert_eq!(&bytes[bytes.len() - len..], &[0x12, 0x34]);
+
+        // Top byte is exactly zero
+        let a = ScalarPrimitive::from_bytes(
+            &hex!("00ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff").into(),
+        )
+        .unwrap();
+        let (bytes, len) = a.to_be_bytes_minimal();
+        assert_eq!(len, 31);
+        assert_eq!(&bytes[bytes.len() - len..], &[0xff; 31]);
+
+        let (_, len) = (-ScalarPrimitive::ONE).to_be_bytes_minimal();
+        assert_eq!(len, 32);
+    }
+
+    #[test]
+    fn from_be_slice_padded() {
+        for len in [1, 2, 8, 17, 31, 32] {
+            let mut bytes = [0u8; 32];
+            bytes[32 - len] = 0x7f;
+            bytes[31] |= 1;
+            let a = ScalarPrimitive::from_bytes(&bytes.into()).unwrap();
+
+            let (encoded, minimal_len) = a.to_be_bytes_minimal();
+            assert_eq!(minimal_len, len);
+
+            let minimal = &encoded[encoded.len() - len..];
+            assert_eq!(ScalarPrimitive::from_be_slice_padded(minimal), Ok(a));
+        }
+
+        assert_eq!(
+            ScalarPrimitive::from_be_slice_padded(&[]),
+            Ok(ScalarPrimitive::ZERO)
+        );
+        assert_eq!(
+            ScalarPrimitive::from_be_slice_padded(&[0]),
+            Ok(ScalarPrimitive::ZERO)
+        );
+        assert!(ScalarPrimitive::from_be_slice_padded(&[0; 33]).is_err());
+        assert!(ScalarPrimitive::from_be_slice_padded(&[0xff; 32]).is_err());
+    }
+
+    #[test]
+    fn le_u64_words_round_trip() {
+        assert_eq!(ScalarPrimitive::LE_U64_WORDS, 4);
+
+        let mut words = [0u64; 4];
+        assert!(ScalarPrimitive::ONE.to_le_u64_words(&mut words).is_ok());
+        assert_eq!(words, [1, 0, 0, 0]);
+
+        assert!((-ScalarPrimitive::ONE).to_le_u64_words(&mut words).is_ok());
+        assert_eq!(
+            words,
+            [
+                0xf3b9cac2fc632550,
+                0xbce6faada7179e84,
+                0xffffffffffffffff,
+                0xffffffff00000000
+            ]
+        );
+        assert_eq!(
+            ScalarPrimitive::from_le_u64_words(&words),
+            Ok(-ScalarPrimitive::ONE)
+        );
+
+        for _ in 0..8 {
+            let a = ScalarPrimitive::random(&mut OsRng);
+            assert!(a.to_le_u64_words(&mut words).is_ok());
+            assert_eq!(ScalarPrimitive::from_le_u64_words(&words), Ok(a));
+        }
+    }
+
+    #[test]
+    fn le_u64_words_rejects_invalid() {
+        let n = [
+            0xf3b9cac2fc632551,
+            0xbce6faada7179e84,
+            0xffffffffffffffff,
+            0xffffffff00000000,
+        ];
+        assert!(ScalarPrimitive::from_le_u64_words(&n).is_err());
+        assert!(ScalarPrimitive::from_le_u64_words(&[1, 0, 0]).is_err());
+        assert!(ScalarPrimitive::from_le_u64_words(&[1, 0, 0, 0, 0]).is_err());
+        assert!(ScalarPrimitive::ONE.to_le_u64_words(&mut [0; 3]).is_err());
+    }
+
+    #[test]
+    fn u64_ops() {
+        let n_minus_one = -ScalarPrimitive::ONE;
+        assert_eq!(n_minus_one + 5, ScalarPrimitive::from(4u8));
+        assert_eq!(ScalarPrimitive::from(3u8) - 5, -ScalarPrimitive::from(2u8));
+        assert_eq!(n_minus_one * 5, -ScalarPrimitive::from(5u8));
+
+        let a = ScalarPrimitive::random(&mut OsRng);
+        let b = u64::MAX;
+        assert_eq!(a + b, a + ScalarPrimitive::from(b));
+        assert_eq!(a - b, a - ScalarPrimitive::from(b));
+        assert_eq!(a * b, a * ScalarPrimitive::from(b));
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a + b);
+        c -= b;
+        assert_eq!(c, a);
+        c *= b;
+        assert_eq!(c, a * b);
+    }
+
+    #[test]
+    fn u64_ops_64_bit_uint() {
+        type Scalar64 = crate::ScalarPrimitive<Curve64>;
+
+        // `u64::MAX` is larger than the order, so it must be reduced first
+        let max = Scalar64::from_uint_reduced(U64::MAX);
+        let a = Scalar64::random(&mut OsRng);
+        assert_eq!(a + u64::MAX, a + max);
+        assert_eq!(a - u64::MAX, a - max);
+        assert_eq!(a * u64::MAX, a * max);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash() {
+        use std::{
+            collections::{hash_map::DefaultHasher, HashSet},
+            hash::{Hash, Hasher},
+        };
+
+        let hash = |scalar: &ScalarPrimitive| {
+            let mut hasher = DefaultHasher::new();
+            scalar.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let a = ScalarPrimitive::random(&mut OsRng);
+        let b = ScalarPrimitive::from_bytes(&a.to_bytes()).unwrap();
+        assert_eq!(hash(&a), hash(&b));
+
+        let set = [a, b, ScalarPrimitive::ONE, ScalarPrimitive::ONE, -a]
+            .into_iter()
+            .collect::<HashSet<_>>();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&a));
+        assert!(set.contains(&ScalarPrimitive::ONE));
+        assert!(set.contains(&-a));
+        assert!(!set.contains(&ScalarPrimitive::ZERO));
+    }
+
+    #[test]
+    fn lincomb() {
+        let [a, b, c, d] = [(); 4].map(|_| ScalarPrimitive::random(&mut OsRng));
+        assert_eq!(ScalarPrimitive::lincomb(&a, &b, &c, &d), a * b + c * d);
+
+        let n_minus_one = -ScalarPrimitive::ONE;
+        assert_eq!(
+            ScalarPrimitive::lincomb(&n_minus_one, &n_minus_one, &n_minus_one, &n_minus_one),
+            ScalarPrimitive::from(2u8)
+        );
+        assert_eq!(
+            ScalarPrimitive::lincomb(&a, &ScalarPrimitive::ZERO, &c, &ScalarPrimitive::ONE),
+            c
+        );
+    }
+
+    #[test]
+    fn checked_div() {
+        let a = ScalarPrimitive::random(&mut OsRng);
+        let b = ScalarPrimitive::random(&mut OsRng);
+
+        assert_eq!((a * b).checked_div(&b).unwrap(), a);
+        assert_eq!(a.checked_div(&a).unwrap(), ScalarPrimitive::ONE);
+        assert!(bool::from(a.checked_div(&ScalarPrimitive::ZERO).is_none()));
+        assert_eq!((a * b) / b, a);
+
+        let mut c = a * b;
+        c /= &b;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn div_by_zero() {
+        let _ = ScalarPrimitive::ONE / ScalarPrimitive::ZERO;
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn num_traits_horner() {
+        use num_traits::{One, Zero};
+
+        /// Evaluate a polynomial with the given coefficients, lowest degree first.
+        fn horner<T: Copy + Zero + One>(coefficients: &[T], x: T) -> T {
+            coefficients
+                .iter()
+                .rev()
+                .fold(T::zero(), |acc, &coefficient| acc * x + coefficient)
+        }
+
+        let x = ScalarPrimitive::random(&mut OsRng);
+        let coefficients = [3u8, 0, 2, 1].map(ScalarPrimitive::from);
+        let expected = ScalarPrimitive::from(3u8) + ScalarPrimitive::from(2u8) * x * x + x * x * x;
+        assert_eq!(horner(&coefficients, x), expected);
+
+        assert!(Zero::is_zero(&ScalarPrimitive::zero()));
+        assert!(ScalarPrimitive::one().is_one());
+    }
+
+    #[test]
+    fn mul_near_modulus() {
+        let n_minus_one = ScalarPrimitive::ZERO - ScalarPrimitive::ONE;
+        assert_eq!(
+            n_minus_one.to_uint(),
+            MockCurve::ORDER.wrapping_sub(&1u64.into())
+        );
+
+        // (n - 1)^2 = (-1)^2 = 1 (mod n)
+        assert_eq!(n_minus_one * n_minus_one, ScalarPrimitive::ONE);
+
+        // (n - 1) * 2 = -2 (mod n)
+        let two = ScalarPrimitive::from(2u64);
+        assert_eq!(n_minus_one * two, -two);
+    }
+
+    #[test]
+    fn mul_identities() {
+        for _ in 0..16 {
+            let a = ScalarPrimitive::random(&mut OsRng);
+            assert_eq!(a * ScalarPrimitive::ONE, a);
+            assert_eq!(a * ScalarPrimitive::ZERO, ScalarPrimitive::ZERO);
+
+            let mut b = a;
+            b *= ScalarPrimitive::from(3u64);
+            assert_eq!(b, a + a + a);
+        }
+    }
+
+    #[test]
+    fn sum() {
+        assert_eq!(
+            core::iter::empty::<ScalarPrimitive>().sum::<ScalarPrimitive>(),
+            ScalarPrimitive::ZERO
+        );
+
+        // Each term is just above n / 2, so the sum wraps the modulus to 1
+        let half = (MockCurve::ORDER >> 1u32).wrapping_add(&1u64.into());
+        let terms = [ScalarPrimitive::new(half).unwrap(); 2];
+        assert_eq!(terms.iter().sum::<ScalarPrimitive>(), ScalarPrimitive::ONE);
+        assert_eq!(
+            terms.into_iter().sum::<ScalarPrimitive>(),
+            ScalarPrimitive::ONE
+        );
+
+        let minus_one = -ScalarPrimitive::ONE;
+        assert_eq!(
+            [minus_one; 4].iter().sum::<ScalarPrimitive>(),
+            -ScalarPrimitive::from(4u64)
+        );
+    }
+
+    #[test]
+    fn product() {
+        assert_eq!(
+            core::iter::empty::<ScalarPrimitive>().product::<ScalarPrimitive>(),
+            ScalarPrimitive::ONE
+        );
+
+        let minus_one = -ScalarPrimitive::ONE;
+        assert_eq!(
+            [minus_one; 3].iter().product::<ScalarPrimitive>(),
+            minus_one
+        );
+        assert_eq!(
+            [minus_one; 4].into_iter().product::<ScalarPrimitive>(),
+            ScalarPrimitive::ONE
+        );
+    }
+
+    #[test]
+    fn invert() {
+        for _ in 0..16 {
+            let a = ScalarPrimitive::random(&mut OsRng);
+            let a_inv = a.invert().unwrap();
+            assert_eq!(a * a_inv, ScalarPrimitive::ONE);
+        }
+
+        let n_minus_one = -ScalarPrimitive::ONE;
+        assert_eq!(n_minus_one.invert().unwrap(), n_minus_one);
+    }
+
+    #[test]
+    fn invert_zero() {
+        assert!(bool::from(ScalarPrimitive::ZERO.invert().is_none()));
+    }
+
+    #[test]
+    fn shr() {
+        let a = ScalarPrimitive::random(&mut OsRng);
+        assert_eq!(a >> 0, a);
+        assert_eq!(a >> 256, ScalarPrimitive::ZERO);
+        assert_eq!(a >> 1000, ScalarPrimitive::ZERO);
+
+        for shift in [1, 7, 64, 65, 255] {
+            assert_eq!((a >> shift).to_uint(), a.to_uint() >> shift);
+        }
+
+        let mut b = a;
+        b >>= 3;
+        assert_eq!(b, a >> 3);
+    }
+
+    #[test]
+    fn double() {
+        let n_minus_one = -ScalarPrimitive::ONE;
+        assert_eq!(n_minus_one.double(), -ScalarPrimitive::from(2u8));
+
+        let a = ScalarPrimitive::random(&mut OsRng);
+        assert_eq!(a.double(), a + a);
+    }
+
+    #[test]
+    fn mul_by_pow2() {
+        let a = ScalarPrimitive::random(&mut OsRng);
+        assert_eq!(a.mul_by_pow2(0), a);
+
+        let mut expected = a;
+        for k in 1..=300 {
+            expected = expected + expected;
+            assert_eq!(a.mul_by_pow2(k), expected);
+        }
+
+        let n_minus_one = -ScalarPrimitive::ONE;
+        assert_eq!(n_minus_one.mul_by_pow2(1), n_minus_one.double());
+    }
+
+    #[test]
+    fn is_high() {
+        let half = MockCurve::ORDER >> 1u32;
+        let half = ScalarPrimitive::new(half).unwrap();
+        assert!(!bool::from(ScalarPrimitive::ZERO.is_high()));
+        assert!(!bool::from(half.is_high()));
+        assert!(bool::from((half + ScalarPrimitive::ONE).is_high()));
+        assert!(bool::from((-ScalarPrimitive::ONE).is_high()));
+    }
+
+    #[test]
+    fn is_high_even_order() {
+        type EvenScalar = crate::ScalarPrimitive<EvenOrderCurve>;
+
+        let half = EvenScalar::from(500u64);
+        assert!(!bool::from(EvenScalar::ZERO.is_high()));
+        assert!(!bool::from(half.is_high()));
+        assert!(bool::from((half + EvenScalar::ONE).is_high()));
+        assert!(bool::from((-EvenScalar::ONE).is_high()));
+    }
+
+    #[test]
+    fn conditional_negate() {
+        for a in [
+            ScalarPrimitive::ZERO,
+            ScalarPrimitive::ONE,
+            ScalarPrimitive::random(&mut OsRng),
+        ] {
+            let mut b = a;
+            b.conditional_negate(Choice::from(0));
+            assert_eq!(b, a);
+
+            b.conditional_negate(Choice::from(1));
+            assert_eq!(b, -a);
+        }
+
+        let mut zero = ScalarPrimitive::ZERO;
+        zero.conditional_negate(Choice::from(1));
+        assert_eq!(zero, ScalarPrimitive::ZERO);
+    }
+
+    #[test]
+    fn normalize_low() {
+        let half = ScalarPrimitive::new(MockCurve::ORDER >> 1u32).unwrap();
+        assert_eq!(half.normalize_low(), half);
+
+        // n - (n / 2 + 1) == n / 2 since n is odd
+        assert_eq!((half + ScalarPrimitive::ONE).normalize_low(), half);
+
+        for _ in 0..16 {
+            let a = ScalarPrimitive::random(&mut OsRng);
+            let low = a.normalize_low();
+            assert!(!bool::from(low.is_high()));
+            assert!(low == a || low == -a);
+        }
+    }
+
+    #[test]
+    fn normalize_low_even_order() {
+        type EvenScalar = crate::ScalarPrimitive<EvenOrderCurve>;
+
+        // n / 2 is its own negation when n is even
+        let half = EvenScalar::from(500u64);
+        assert_eq!(half.normalize_low(), half);
+        assert_eq!(-half, half);
+
+        let half_plus_one = half + EvenScalar::ONE;
+        assert_eq!(half_plus_one.normalize_low(), EvenScalar::from(499u64));
+        assert_eq!((-EvenScalar::ONE).normalize_low(), EvenScalar::ONE);
+    }
+
+    #[test]
+    fn div2_mod() {
+        let n_minus_two = -ScalarPrimitive::from(2u64);
+        let half = n_minus_two.div2_mod();
+        assert_eq!(half, -ScalarPrimitive::ONE);
+        assert_eq!(half + half, n_minus_two);
+
+        let n_minus_one = -ScalarPrimitive::ONE;
+        assert_eq!(n_minus_one.div2_mod().to_uint(), n_minus_one.to_uint() >> 1);
+
+        for _ in 0..16 {
+            let a = ScalarPrimitive::random(&mut OsRng);
+            let half = a.div2_mod();
+            assert_eq!(half + half, a);
+        }
+    }
+
+    #[test]
+    fn pow() {
+        let n_minus_two = MockCurve::ORDER.wrapping_sub(&2u64.into());
+
+        for _ in 0..8 {
+            let a = ScalarPrimitive::random(&mut OsRng);
+            assert_eq!(a.pow(&0u64.into()), ScalarPrimitive::ONE);
+            assert_eq!(a.pow(&1u64.into()), a);
+            assert_eq!(a.pow(&n_minus_two), a.invert().unwrap());
+
+            let exp = ScalarPrimitive::random(&mut OsRng).to_uint();
+            assert_eq!(a.pow(&exp), a.pow_vartime(&exp));
+        }
+    }
+
+    #[test]
+    fn pow_vartime() {
+        let a = ScalarPrimitive::random(&mut OsRng);
+        assert_eq!(a.pow_vartime(&0u64.into()), ScalarPrimitive::ONE);
+        assert_eq!(a.pow_vartime(&1u64.into()), a);
+        assert_eq!(a.pow_vartime(&3u64.into()), a * a * a);
+    }
+
+    /// Recompute a scalar from its wNAF digits, checking their invariants.
+    fn from_wnaf(digits: impl Iterator<Item = i8>, w: usize) -> ScalarPrimitive {
+        let mut buf = [0i8; 257];
+        let mut len = 0;
+
+        for digit in digits {
+            buf[len] = digit;
+            len += 1;
+        }
+
+        assert_eq!(len, buf.len());
+        let digits = &buf;
+
+        for (i, &digit) in digits.iter().enumerate() {
+            if digit != 0 {
+                assert_eq!(digit & 1, 1);
+                assert!(i32::from(digit).abs() < 1 << (w - 1));
+                assert!(digits[i + 1..].iter().take(w - 1).all(|&d| d == 0));
+            }
+        }
+
+        digits
+            .iter()
+            .rev()
+            .fold(ScalarPrimitive::ZERO, |acc, &digit| {
+                let abs = ScalarPrimitive::from(digit.unsigned_abs());
+                let digit = if digit < 0 { -abs } else { abs };
+                acc.double() + digit
+            })
+    }
+
+    #[test]
+    fn to_naf_vartime() {
+        let n_minus_one = -ScalarPrimitive::ONE;
+        assert_eq!(from_wnaf(n_minus_one.to_naf_vartime(), 2), n_minus_one);
+
+        for _ in 0..8 {
+            let a = ScalarPrimitive::random(&mut OsRng);
+            assert_eq!(from_wnaf(a.to_naf_vartime(), 2), a);
+        }
+    }
+
+    #[test]
+    fn to_wnaf_vartime() {
+        for w in 2..=8 {
+            for a in [
+                ScalarPrimitive::ZERO,
+                ScalarPrimitive::ONE,
+                -ScalarPrimitive::ONE,
+                ScalarPrimitive::random(&mut OsRng),
+            ] {
+                assert_eq!(from_wnaf(a.to_wnaf_vartime(w), w), a);
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt() {
+        for _ in 0..8 {
+            let a = ScalarPrimitive::random(&mut OsRng);
+            let a2 = a * a;
+            let root = a2.sqrt().unwrap();
+            assert_eq!(root * root, a2);
+            assert!(root == a || root == -a);
+        }
+
+        assert_eq!(ScalarPrimitive::ZERO.sqrt().unwrap(), ScalarPrimitive::ZERO);
+    }
+
+    #[test]
+    fn sqrt_non_residue() {
+        // 7 is a quadratic non-residue modulo the mock curve order
+        let seven = ScalarPrimitive::from(7u64);
+        assert!(bool::from(seven.sqrt().is_none()));
+    }
+
+    #[test]
+    fn sqrt_order_3_mod_4() {
+        type Scalar64 = crate::ScalarPrimitive<Curve64Mod4Is3>;
+
+        for _ in 0..8 {
+            let a = Scalar64::random(&mut OsRng);
+            let a2 = a * a;
+            let root = a2.sqrt().unwrap();
+            assert_eq!(root * root, a2);
+            assert!(root == a || root == -a);
+        }
+
+        assert_eq!(Scalar64::ZERO.sqrt().unwrap(), Scalar64::ZERO);
+
+        // 2 is a quadratic non-residue modulo the order
+        assert!(bool::from(Scalar64::from(2u64).sqrt().is_none()));
     }
 }
@@ -4,12 +4,27 @@
 
 pub use sec1::point::{Coordinates, ModulusSize, Tag};
 
-use crate::{Curve, FieldBytesSize, Result, SecretKey};
+use crate::{Curve, Error, FieldBytesSize, Result, SecretKey};
+use core::fmt;
 use hybrid_array::Array;
+use sec1::der::{self, asn1::ObjectIdentifier};
 use subtle::CtOption;
 
 #[cfg(feature = "arithmetic")]
-use crate::{AffinePoint, CurveArithmetic, Error};
+use crate::{AffinePoint, CurveArithmetic};
+
+#[cfg(all(feature = "arithmetic", feature = "pkcs8"))]
+use hybrid_array::typenum::Unsigned;
+
+#[cfg(feature = "pkcs8")]
+use {
+    crate::ALGORITHM_OID,
+    pkcs8::{
+        der::Tagged,
+        spki::{self, AlgorithmIdentifierRef},
+        AssociatedOid,
+    },
+};
 
 /// Encoded elliptic curve point with point compression.
 pub type CompressedPoint<C> = Array<u8, CompressedPointSize<C>>;
@@ -112,3 +127,329 @@ where
         }
     }
 }
+
+/// Hybrid point encoding tag for points with an even y-coordinate.
+pub const HYBRID_EVEN_Y_TAG: u8 = 0x06;
+
+/// Hybrid point encoding tag for points with an odd y-coordinate.
+pub const HYBRID_ODD_Y_TAG: u8 = 0x07;
+
+/// Support for the "hybrid" point encoding from ANSI X9.62.
+///
+/// A hybrid point has both affine coordinates like an uncompressed point,
+/// but its tag ([`HYBRID_EVEN_Y_TAG`] or [`HYBRID_ODD_Y_TAG`]) also carries
+/// the parity of the y-coordinate like a compressed point. SEC1 doesn't
+/// define this encoding, so [`EncodedPoint`] can't represent it: hybrid
+/// points are decoded as uncompressed ones after checking the parity.
+pub trait HybridPoint: Sized {
+    /// Serialized hybrid point, which is the same size as an uncompressed
+    /// point.
+    type Bytes: AsRef<[u8]>;
+
+    /// Decode a hybrid point, returning it as an uncompressed point.
+    ///
+    /// Returns an error if the tag isn't a hybrid tag, the length is wrong,
+    /// or the parity implied by the tag doesn't match the y-coordinate.
+    fn from_hybrid_bytes(bytes: &[u8]) -> Result<Self>;
+
+    /// Serialize this point in hybrid form.
+    ///
+    /// Returns `None` unless this is an uncompressed point.
+    fn to_hybrid(&self) -> Option<Self::Bytes>;
+}
+
+impl<Size> HybridPoint for sec1::point::EncodedPoint<Size>
+where
+    Size: ModulusSize,
+{
+    type Bytes = Array<u8, Size::UncompressedPointSize>;
+
+    fn from_hybrid_bytes(bytes: &[u8]) -> Result<Self> {
+        let (&tag, coordinates) = bytes.split_first().ok_or(Error)?;
+
+        if coordinates.len() != Size::USIZE * 2 {
+            return Err(Error);
+        }
+
+        let (x, y) = coordinates.split_at(Size::USIZE);
+        let y_is_odd = y.last().ok_or(Error)? & 1;
+
+        match tag {
+            HYBRID_EVEN_Y_TAG if y_is_odd == 0 => {}
+            HYBRID_ODD_Y_TAG if y_is_odd == 1 => {}
+            _ => return Err(Error),
+        }
+
+        let x = Array::try_from(x).map_err(|_| Error)?;
+        let y = Array::try_from(y).map_err(|_| Error)?;
+        Ok(Self::from_affine_coordinates(&x, &y, false))
+    }
+
+    fn to_hybrid(&self) -> Option<Self::Bytes> {
+        match self.coordinates() {
+            Coordinates::Uncompressed { y, .. } => {
+                let mut bytes = Self::Bytes::default();
+                bytes.copy_from_slice(self.as_bytes());
+                bytes[0] = HYBRID_EVEN_Y_TAG | (y.last()? & 1);
+                Some(bytes)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Error returned when the algorithm or curve parameters of a key don't
+/// identify the expected named curve.
+///
+/// The `AlgorithmIdentifier` of PKCS#8 and SPKI documents and the optional
+/// `parameters` of SEC1 `ECPrivateKey` documents are checked when decoding
+/// keys. The `spki` and `der` error types used by the decoding traits can't
+/// carry the expected OID, so this error is converted as follows:
+///
+/// | [`ParametersError`]             | [`spki::Error`]                         | [`der::Error`] kind               |
+/// |---------------------------------|-----------------------------------------|-----------------------------------|
+/// | `AlgorithmMismatch`             | `OidUnknown { oid: found }`             | `OidUnknown { oid: found }`       |
+/// | `CurveMismatch`                 | `Asn1` with `OidUnknown { oid: found }` | `OidUnknown { oid: found }`       |
+/// | `ExplicitParametersUnsupported` | `Asn1` with `Value { tag: Sequence }`   | `Value { tag: Sequence }`         |
+/// | `ParametersMissing`             | `AlgorithmParametersMissing`            | `Value { tag: ObjectIdentifier }` |
+/// | `Asn1`                          | `Asn1`                                  | unchanged                         |
+///
+/// Use [`validate_algorithm_identifier`] to get the full error for a parsed
+/// `AlgorithmIdentifier`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParametersError {
+    /// The algorithm isn't `id-ecPublicKey`.
+    AlgorithmMismatch {
+        /// `id-ecPublicKey`.
+        expected: ObjectIdentifier,
+
+        /// Algorithm OID found in the document.
+        found: ObjectIdentifier,
+    },
+
+    /// The named curve isn't the curve the key is being decoded for.
+    CurveMismatch {
+        /// OID of the curve the key is being decoded for.
+        expected: ObjectIdentifier,
+
+        /// Named curve OID found in the document.
+        found: ObjectIdentifier,
+    },
+
+    /// The key uses explicit curve parameters (`SpecifiedECDomain`) rather
+    /// than a named curve, which isn't supported.
+    ExplicitParametersUnsupported,
+
+    /// The `AlgorithmIdentifier` has no parameters.
+    ParametersMissing,
+
+    /// The parameters are missing or malformed.
+    Asn1(der::Error),
+}
+
+impl fmt::Display for ParametersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlgorithmMismatch { expected, found } => {
+                write!(f, "algorithm mismatch: expected {expected}, found {found}")
+            }
+            Self::CurveMismatch { expected, found } => {
+                write!(f, "curve mismatch: expected {expected}, found {found}")
+            }
+            Self::ExplicitParametersUnsupported => {
+                f.write_str("explicit elliptic curve parameters are unsupported")
+            }
+            Self::ParametersMissing => f.write_str("elliptic curve parameters missing"),
+            Self::Asn1(err) => write!(f, "malformed curve parameters: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParametersError {}
+
+impl From<der::Error> for ParametersError {
+    fn from(err: der::Error) -> Self {
+        Self::Asn1(err)
+    }
+}
+
+impl From<ParametersError> for der::Error {
+    fn from(err: ParametersError) -> der::Error {
+        match err {
+            ParametersError::AlgorithmMismatch { found, .. }
+            | ParametersError::CurveMismatch { found, .. } => {
+                der::ErrorKind::OidUnknown { oid: found }.into()
+            }
+            ParametersError::ExplicitParametersUnsupported => der::ErrorKind::Value {
+                tag: der::Tag::Sequence,
+            }
+            .into(),
+            ParametersError::ParametersMissing => der::Tag::ObjectIdentifier.value_error(),
+            ParametersError::Asn1(err) => err,
+        }
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl From<ParametersError> for spki::Error {
+    fn from(err: ParametersError) -> spki::Error {
+        match err {
+            ParametersError::AlgorithmMismatch { found, .. } => {
+                spki::Error::OidUnknown { oid: found }
+            }
+            // Constructed directly: `From<der::Error>` would turn an unknown
+            // OID into `spki::Error::OidUnknown`, i.e. an algorithm mismatch.
+            err @ (ParametersError::CurveMismatch { .. }
+            | ParametersError::ExplicitParametersUnsupported) => spki::Error::Asn1(err.into()),
+            ParametersError::ParametersMissing => spki::Error::AlgorithmParametersMissing,
+            ParametersError::Asn1(err) => err.into(),
+        }
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl From<ParametersError> for pkcs8::Error {
+    fn from(err: ParametersError) -> pkcs8::Error {
+        pkcs8::Error::PublicKey(err.into())
+    }
+}
+
+/// Check that a named curve OID is `expected`.
+pub(crate) fn validate_named_curve(
+    expected: ObjectIdentifier,
+    found: ObjectIdentifier,
+) -> core::result::Result<(), ParametersError> {
+    if found == expected {
+        Ok(())
+    } else {
+        Err(ParametersError::CurveMismatch { expected, found })
+    }
+}
+
+/// Check that a PKCS#8 or SPKI [`AlgorithmIdentifierRef`] identifies an
+/// `id-ecPublicKey` on the named curve `C`.
+///
+/// See [`ParametersError`] for the possible errors.
+#[cfg(feature = "pkcs8")]
+pub fn validate_algorithm_identifier<C: AssociatedOid>(
+    algorithm: &AlgorithmIdentifierRef<'_>,
+) -> core::result::Result<(), ParametersError> {
+    if algorithm.oid != ALGORITHM_OID {
+        return Err(ParametersError::AlgorithmMismatch {
+            expected: ALGORITHM_OID,
+            found: algorithm.oid,
+        });
+    }
+
+    let parameters = algorithm
+        .parameters
+        .ok_or(ParametersError::ParametersMissing)?;
+
+    match parameters.tag() {
+        der::Tag::ObjectIdentifier => {
+            validate_named_curve(C::OID, ObjectIdentifier::try_from(parameters)?)
+        }
+        der::Tag::Sequence => Err(ParametersError::ExplicitParametersUnsupported),
+        tag => Err(tag
+            .unexpected_error(Some(der::Tag::ObjectIdentifier))
+            .into()),
+    }
+}
+
+/// Length of an ASN.1 DER TLV (tag-length-value) encoding whose value is
+/// `value_len` bytes, for values shorter than 64 KiB.
+#[cfg(all(feature = "arithmetic", feature = "pkcs8"))]
+pub(crate) const fn der_tlv_len(value_len: usize) -> usize {
+    let header_len = if value_len < 0x80 {
+        2
+    } else if value_len < 0x100 {
+        3
+    } else {
+        4
+    };
+
+    header_len + value_len
+}
+
+/// Length of the ASN.1 DER encoding of the `AlgorithmIdentifier` for an
+/// `id-ecPublicKey` on the named curve `C`.
+#[cfg(all(feature = "arithmetic", feature = "pkcs8"))]
+pub(crate) const fn algorithm_identifier_der_len<C: AssociatedOid>() -> usize {
+    der_tlv_len(der_tlv_len(ALGORITHM_OID.as_bytes().len()) + der_tlv_len(C::OID.as_bytes().len()))
+}
+
+/// Length of the ASN.1 DER encoding of an uncompressed point on the curve `C`
+/// as a `BIT STRING`.
+#[cfg(all(feature = "arithmetic", feature = "pkcs8"))]
+pub(crate) const fn uncompressed_point_bit_string_der_len<C: Curve>() -> usize
+where
+    FieldBytesSize<C>: ModulusSize,
+{
+    der_tlv_len(1 + UncompressedPointSize::<C>::USIZE)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::{HybridPoint, HYBRID_EVEN_Y_TAG, HYBRID_ODD_Y_TAG};
+    use hex_literal::hex;
+    use hybrid_array::typenum::U32;
+
+    type EncodedPoint = sec1::point::EncodedPoint<U32>;
+
+    /// NIST P-256 generator, whose y-coordinate is odd.
+    const GENERATOR: [u8; 65] = hex!("046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c2964fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5");
+
+    /// 3 times the NIST P-256 generator, whose y-coordinate is even.
+    const THREE_G: [u8; 65] = hex!("045ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c8734640c4998ff7e374b06ce1a64a2ecd82ab036384fb83d9a79b127a27d5032");
+
+    fn hybrid(uncompressed: [u8; 65], tag: u8) -> [u8; 65] {
+        let mut bytes = uncompressed;
+        bytes[0] = tag;
+        bytes
+    }
+
+    #[test]
+    fn decode_hybrid() {
+        for (uncompressed, tag) in [(GENERATOR, HYBRID_ODD_Y_TAG), (THREE_G, HYBRID_EVEN_Y_TAG)] {
+            let point = EncodedPoint::from_hybrid_bytes(&hybrid(uncompressed, tag)).unwrap();
+            assert_eq!(point, EncodedPoint::from_bytes(uncompressed).unwrap());
+        }
+    }
+
+    #[test]
+    fn decode_hybrid_parity_mismatch() {
+        for (uncompressed, tag) in [(GENERATOR, HYBRID_EVEN_Y_TAG), (THREE_G, HYBRID_ODD_Y_TAG)] {
+            assert!(EncodedPoint::from_hybrid_bytes(&hybrid(uncompressed, tag)).is_err());
+        }
+    }
+
+    #[test]
+    fn decode_hybrid_rejects_other_encodings() {
+        assert!(EncodedPoint::from_hybrid_bytes(&GENERATOR).is_err());
+        assert!(EncodedPoint::from_hybrid_bytes(&[]).is_err());
+        assert!(EncodedPoint::from_hybrid_bytes(&[HYBRID_ODD_Y_TAG]).is_err());
+
+        let hybrid = hybrid(GENERATOR, HYBRID_ODD_Y_TAG);
+        assert!(EncodedPoint::from_hybrid_bytes(&hybrid[..64]).is_err());
+    }
+
+    #[test]
+    fn hybrid_round_trip() {
+        for (uncompressed, tag) in [(GENERATOR, HYBRID_ODD_Y_TAG), (THREE_G, HYBRID_EVEN_Y_TAG)] {
+            let point = EncodedPoint::from_bytes(uncompressed).unwrap();
+            let hybrid = point.to_hybrid().unwrap();
+            assert_eq!(hybrid.as_slice(), &self::hybrid(uncompressed, tag));
+            assert_eq!(EncodedPoint::from_hybrid_bytes(&hybrid).unwrap(), point);
+        }
+    }
+
+    #[test]
+    fn to_hybrid_requires_uncompressed() {
+        let point = EncodedPoint::from_bytes(GENERATOR).unwrap();
+        assert!(point.compress().to_hybrid().is_none());
+        assert!(EncodedPoint::identity().to_hybrid().is_none());
+    }
+}
@@ -3,22 +3,34 @@
 use super::SecretKey;
 use crate::{
     pkcs8::{self, der::Decode, AssociatedOid},
-    sec1::{ModulusSize, ValidatePublicKey},
+    sec1::{validate_algorithm_identifier, EncodedPoint, ModulusSize, ValidatePublicKey},
     Curve, FieldBytesSize, ALGORITHM_OID,
 };
 use pkcs8::spki::{AlgorithmIdentifier, AssociatedAlgorithmIdentifier, ObjectIdentifier};
 use sec1::EcPrivateKey;
 
-// Imports for the `EncodePrivateKey` impl
-#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+// Imports for encoding support
+#[cfg(feature = "arithmetic")]
 use {
     crate::{
-        sec1::{FromEncodedPoint, ToEncodedPoint},
+        sec1::{
+            algorithm_identifier_der_len, der_tlv_len, uncompressed_point_bit_string_der_len,
+            FromEncodedPoint, ToEncodedPoint,
+        },
         AffinePoint, CurveArithmetic,
     },
-    pkcs8::{der, EncodePrivateKey},
+    hybrid_array::typenum::Unsigned,
+    pkcs8::{der, Version},
+    sec1::der::Encode,
 };
 
+// Imports for the `EncodePrivateKey` impl
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+use {crate::PublicKey, pkcs8::EncodePrivateKey, zeroize::Zeroizing};
+
+#[cfg(all(feature = "arithmetic", feature = "pkcs8-encryption"))]
+use pkcs8::pkcs5::pbes2;
+
 // Imports for actual PEM support
 #[cfg(feature = "pem")]
 use {
@@ -47,12 +59,21 @@ where
     type Error = pkcs8::Error;
 
     fn try_from(private_key_info: pkcs8::PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
-        private_key_info
-            .algorithm
-            .assert_oids(ALGORITHM_OID, C::OID)?;
+        validate_algorithm_identifier::<C>(&private_key_info.algorithm)?;
 
         let ec_private_key = EcPrivateKey::from_der(private_key_info.private_key)?;
-        Ok(Self::try_from(ec_private_key)?)
+        let secret_key = Self::try_from(ec_private_key)?;
+
+        // PKCS#8 v2 (`OneAsymmetricKey`) documents may carry the public key
+        if let Some(public_key) = private_key_info.public_key {
+            let public_key = EncodedPoint::<C>::from_bytes(public_key)
+                .map_err(|_| pkcs8::Error::KeyMalformed)?;
+
+            C::validate_public_key(&secret_key, &public_key)
+                .map_err(|_| pkcs8::Error::KeyMalformed)?;
+        }
+
+        Ok(secret_key)
     }
 }
 
@@ -64,15 +85,134 @@ where
     FieldBytesSize<C>: ModulusSize,
 {
     fn to_pkcs8_der(&self) -> pkcs8::Result<der::SecretDocument> {
+        self.to_pkcs8_der_with_version(Version::V1)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+impl<C> SecretKey<C>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    /// Serialize this secret key as a PKCS#8 document of the given version.
+    ///
+    /// [`EncodePrivateKey::to_pkcs8_der`] produces PKCS#8 v1 (`PrivateKeyInfo`)
+    /// documents. PKCS#8 v2 (`OneAsymmetricKey`) documents additionally include
+    /// the public key in the outer structure, which some parsers require.
+    pub fn to_pkcs8_der_with_version(
+        &self,
+        version: Version,
+    ) -> pkcs8::Result<der::SecretDocument> {
+        self.to_pkcs8_der_with_public_key(&self.public_key(), version)
+    }
+
+    /// Serialize this secret key as a PKCS#8 document of the given version,
+    /// using a precomputed `public_key` which must correspond to it.
+    pub(crate) fn to_pkcs8_der_with_public_key(
+        &self,
+        public_key: &PublicKey<C>,
+        version: Version,
+    ) -> pkcs8::Result<der::SecretDocument> {
         // TODO(tarcieri): make `PrivateKeyInfo` generic around `Params`
         let algorithm_identifier = pkcs8::AlgorithmIdentifierRef {
             oid: ALGORITHM_OID,
             parameters: Some((&C::OID).into()),
         };
 
-        let ec_private_key = self.to_sec1_der()?;
-        let pkcs8_key = pkcs8::PrivateKeyInfo::new(algorithm_identifier, &ec_private_key);
-        Ok(der::SecretDocument::encode_msg(&pkcs8_key)?)
+        self.with_ec_private_key(public_key, |ec_private_key| {
+            let private_key = Zeroizing::new(ec_private_key.to_der()?);
+
+            let pkcs8_key = pkcs8::PrivateKeyInfo {
+                algorithm: algorithm_identifier,
+                private_key: &private_key,
+                public_key: match version {
+                    Version::V1 => None,
+                    Version::V2 => ec_private_key.public_key,
+                },
+            };
+
+            Ok(der::SecretDocument::encode_msg(&pkcs8_key)?)
+        })
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> SecretKey<C>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    /// Maximum length of the PKCS#8 v1 ASN.1 DER encoding of a secret key for
+    /// this curve, i.e. the buffer size needed by
+    /// [`SecretKey::write_pkcs8_der`].
+    pub const MAX_PKCS8_DER_LEN: usize = {
+        // `ECPrivateKey` with the version, secret scalar, and `[1]` public key
+        let ec_private_key_len = der_tlv_len(
+            3 + der_tlv_len(C::FieldBytesSize::USIZE)
+                + der_tlv_len(uncompressed_point_bit_string_der_len::<C>()),
+        );
+
+        der_tlv_len(3 + algorithm_identifier_der_len::<C>() + der_tlv_len(ec_private_key_len))
+    };
+
+    /// Serialize this secret key as a PKCS#8 v1 ASN.1 DER document into the
+    /// provided buffer, without requiring `alloc`.
+    ///
+    /// Returns the prefix of `buf` containing the encoded document, which is
+    /// identical to the output of `EncodePrivateKey::to_pkcs8_der`, or an
+    /// error if `buf` is shorter than the encoding.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// The buffer contains key material and isn't zeroized by this function:
+    /// callers are responsible for zeroizing it after use.
+    pub fn write_pkcs8_der<'a>(&self, buf: &'a mut [u8]) -> pkcs8::Result<&'a [u8]> {
+        let algorithm_identifier = pkcs8::AlgorithmIdentifierRef {
+            oid: ALGORITHM_OID,
+            parameters: Some((&C::OID).into()),
+        };
+
+        self.with_ec_private_key(&self.public_key(), |ec_private_key| {
+            // The `ECPrivateKey` is encoded directly into `buf` as the contents
+            // of the `privateKey` OCTET STRING, avoiding an intermediate buffer
+            let ec_private_key_len = ec_private_key.encoded_len()?;
+            let private_key_header = der::Header::new(der::Tag::OctetString, ec_private_key_len)?;
+            let len = ((Version::V1.encoded_len()? + algorithm_identifier.encoded_len()?)?
+                + private_key_header.encoded_len()?)?;
+
+            let mut writer = der::SliceWriter::new(buf);
+            writer.sequence((len + ec_private_key_len)?, |writer| {
+                Version::V1.encode(writer)?;
+                algorithm_identifier.encode(writer)?;
+                private_key_header.encode(writer)?;
+                ec_private_key.encode(writer)
+            })?;
+
+            Ok(writer.finish()?)
+        })
+    }
+}
+
+#[cfg(all(feature = "arithmetic", feature = "pkcs8-encryption"))]
+impl<C> SecretKey<C>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    /// Serialize this secret key as an encrypted PKCS#8 document using the
+    /// given PBES2 parameters, rather than the defaults selected by
+    /// [`EncodePrivateKey::to_pkcs8_encrypted_der`].
+    pub fn to_pkcs8_encrypted_der_with_params(
+        &self,
+        params: pbes2::Parameters,
+        password: impl AsRef<[u8]>,
+    ) -> pkcs8::Result<der::SecretDocument> {
+        let der = self.to_pkcs8_der()?;
+        pkcs8::PrivateKeyInfo::try_from(der.as_bytes())?.encrypt_with_params(params, password)
     }
 }
 
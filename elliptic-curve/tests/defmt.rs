@@ -0,0 +1,18 @@
+//! `defmt` tests
+
+#![cfg(all(feature = "dev", feature = "defmt"))]
+
+use elliptic_curve::{
+    dev::{PublicKey, ScalarPrimitive, SecretKey},
+    Error,
+};
+
+fn assert_format<T: defmt::Format>() {}
+
+#[test]
+fn impls_format() {
+    assert_format::<Error>();
+    assert_format::<ScalarPrimitive>();
+    assert_format::<SecretKey>();
+    assert_format::<PublicKey>();
+}
@@ -3,9 +3,9 @@
 #![cfg(all(feature = "dev", feature = "pkcs8"))]
 
 use elliptic_curve::{
-    dev::{PublicKey, SecretKey},
-    pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey},
-    sec1::ToEncodedPoint,
+    dev::{MockCurve, PublicKey, SecretKey},
+    pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey},
+    sec1::{validate_algorithm_identifier, ParametersError, ToEncodedPoint},
 };
 use hex_literal::hex;
 use pkcs8::der;
@@ -55,3 +55,379 @@ fn decode_pkcs8_public_key_from_pem() {
     let der_key = PublicKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
     assert_eq!(public_key, der_key);
 }
+
+#[test]
+#[cfg(feature = "pem")]
+fn public_key_pem_round_trip() {
+    let public_key = PublicKey::from_public_key_pem(PKCS8_PUBLIC_KEY_PEM).unwrap();
+    let pem = public_key.to_public_key_pem(Default::default()).unwrap();
+    assert_eq!(pem.trim_end(), PKCS8_PUBLIC_KEY_PEM.trim_end());
+    assert_eq!(PublicKey::from_public_key_pem(&pem).unwrap(), public_key);
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn parse_public_key_from_hex() {
+    let expected = PublicKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    let hex = "041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F";
+
+    for s in [hex.to_owned(), hex.to_lowercase()] {
+        let public_key = s.parse::<PublicKey>().unwrap();
+        assert_eq!(
+            public_key.to_encoded_point(false),
+            expected.to_encoded_point(false)
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn parse_public_key_rejects_garbage() {
+    for s in [
+        "",
+        "04",
+        "zz",
+        " 041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F",
+        "-----BEGIN PUBLIC KEY-----\ngarbage\n-----END PUBLIC KEY-----\n",
+        "-----BEGIN PUBLIC KEY-----",
+    ] {
+        assert!(s.parse::<PublicKey>().is_err(), "{s:?}");
+    }
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn parse_public_key_rejects_other_pem_labels() {
+    let pem = PKCS8_PUBLIC_KEY_PEM.replace("PUBLIC KEY", "PRIVATE KEY");
+    assert!(pem.parse::<PublicKey>().is_err());
+    assert!(PublicKey::from_public_key_pem(&pem).is_err());
+}
+
+/// DER-encoded PKCS#8 private key encrypted with
+/// `openssl pkcs8 -topk8 -v2 aes-256-cbc` under [`PKCS8_ENCRYPTION_PASSWORD`].
+#[cfg(feature = "pkcs8-encryption")]
+const PKCS8_ENCRYPTED_PRIVATE_KEY_DER: &[u8] =
+    include_bytes!("examples/pkcs8-encrypted-private-key.der");
+
+/// PEM-encoded PKCS#8 private key encrypted with
+/// `openssl pkcs8 -topk8 -v2 aes-256-cbc` under [`PKCS8_ENCRYPTION_PASSWORD`].
+#[cfg(all(feature = "pem", feature = "pkcs8-encryption"))]
+const PKCS8_ENCRYPTED_PRIVATE_KEY_PEM: &str =
+    include_str!("examples/pkcs8-encrypted-private-key.pem");
+
+/// Password for the encrypted PKCS#8 examples
+#[cfg(feature = "pkcs8-encryption")]
+const PKCS8_ENCRYPTION_PASSWORD: &str = "hunter42";
+
+/// Secret scalar of the encrypted PKCS#8 examples
+#[cfg(feature = "pkcs8-encryption")]
+const PKCS8_ENCRYPTED_SCALAR: [u8; 32] =
+    hex!("2B71808D767C7FE18372A9E3C101F3EA82EA04885E69E9E7CDE9F8F82EBAA5AC");
+
+#[test]
+#[cfg(feature = "pkcs8-encryption")]
+fn decode_encrypted_pkcs8_private_key_from_der() {
+    let secret_key = SecretKey::from_pkcs8_encrypted_der(
+        PKCS8_ENCRYPTED_PRIVATE_KEY_DER,
+        PKCS8_ENCRYPTION_PASSWORD,
+    )
+    .unwrap();
+    assert_eq!(secret_key.to_bytes().as_slice(), &PKCS8_ENCRYPTED_SCALAR);
+}
+
+#[test]
+#[cfg(all(feature = "pem", feature = "pkcs8-encryption"))]
+fn decode_encrypted_pkcs8_private_key_from_pem() {
+    let secret_key = SecretKey::from_pkcs8_encrypted_pem(
+        PKCS8_ENCRYPTED_PRIVATE_KEY_PEM,
+        PKCS8_ENCRYPTION_PASSWORD,
+    )
+    .unwrap();
+    assert_eq!(secret_key.to_bytes().as_slice(), &PKCS8_ENCRYPTED_SCALAR);
+}
+
+#[test]
+#[cfg(feature = "pkcs8-encryption")]
+fn decode_encrypted_pkcs8_private_key_wrong_password() {
+    let err = SecretKey::from_pkcs8_encrypted_der(PKCS8_ENCRYPTED_PRIVATE_KEY_DER, "hunter2")
+        .unwrap_err();
+    assert!(matches!(err, pkcs8::Error::EncryptedPrivateKey(_)));
+}
+
+#[test]
+#[cfg(feature = "pkcs8-encryption")]
+fn decode_encrypted_pkcs8_private_key_malformed() {
+    let der = &PKCS8_ENCRYPTED_PRIVATE_KEY_DER[..PKCS8_ENCRYPTED_PRIVATE_KEY_DER.len() - 1];
+    let err = SecretKey::from_pkcs8_encrypted_der(der, PKCS8_ENCRYPTION_PASSWORD).unwrap_err();
+    assert!(matches!(err, pkcs8::Error::Asn1(_)));
+}
+
+/// Cheap PBES2 parameters to keep the round trip tests fast.
+#[cfg(feature = "pkcs8-encryption")]
+fn example_pbes2_params() -> pkcs8::pkcs5::pbes2::Parameters {
+    pkcs8::pkcs5::pbes2::Parameters::pbkdf2_sha256_aes256cbc(2048, &[0x42; 8], [0x24; 16]).unwrap()
+}
+
+#[test]
+#[cfg(feature = "pkcs8-encryption")]
+fn encrypted_pkcs8_private_key_round_trip() {
+    let secret_key = SecretKey::from_slice(&EXAMPLE_SCALAR).unwrap();
+    let der = secret_key
+        .to_pkcs8_encrypted_der_with_params(example_pbes2_params(), PKCS8_ENCRYPTION_PASSWORD)
+        .unwrap();
+
+    let decoded =
+        SecretKey::from_pkcs8_encrypted_der(der.as_bytes(), PKCS8_ENCRYPTION_PASSWORD).unwrap();
+    assert_eq!(decoded, secret_key);
+}
+
+#[test]
+#[cfg(all(feature = "pem", feature = "pkcs8-encryption"))]
+fn encrypted_pkcs8_private_key_pem_round_trip() {
+    let secret_key = SecretKey::from_slice(&EXAMPLE_SCALAR).unwrap();
+    let pem = secret_key
+        .to_pkcs8_encrypted_der_with_params(example_pbes2_params(), PKCS8_ENCRYPTION_PASSWORD)
+        .unwrap()
+        .to_pem("ENCRYPTED PRIVATE KEY", Default::default())
+        .unwrap();
+
+    let decoded = SecretKey::from_pkcs8_encrypted_pem(&pem, PKCS8_ENCRYPTION_PASSWORD).unwrap();
+    assert_eq!(decoded, secret_key);
+}
+
+/// DER-encoded PKCS#8 private key for P-384 (i.e. the wrong curve)
+const P384_PKCS8_PRIVATE_KEY_DER: &[u8] = include_bytes!("examples/p384-pkcs8-private-key.der");
+
+/// DER-encoded SPKI public key for P-384 (i.e. the wrong curve)
+const P384_PKCS8_PUBLIC_KEY_DER: &[u8] = include_bytes!("examples/p384-pkcs8-public-key.der");
+
+/// DER-encoded PKCS#8 private key with explicit P-256 curve parameters
+const EXPLICIT_PARAMS_PKCS8_PRIVATE_KEY_DER: &[u8] =
+    include_bytes!("examples/pkcs8-private-key-explicit-params.der");
+
+/// DER-encoded SPKI public key with explicit P-256 curve parameters
+const EXPLICIT_PARAMS_PKCS8_PUBLIC_KEY_DER: &[u8] =
+    include_bytes!("examples/pkcs8-public-key-explicit-params.der");
+
+/// OID for the P-384 curve (`secp384r1`)
+const SECP384R1_OID: pkcs8::ObjectIdentifier = pkcs8::ObjectIdentifier::new_unwrap("1.3.132.0.34");
+
+/// OID for the P-256 curve (`prime256v1`)
+const PRIME256V1_OID: pkcs8::ObjectIdentifier =
+    pkcs8::ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+
+/// OID for RSA encryption, i.e. the wrong algorithm
+const RSA_ENCRYPTION_OID: pkcs8::ObjectIdentifier =
+    pkcs8::ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+
+/// `spki` error for a named curve other than the expected one.
+fn curve_mismatch(found: pkcs8::ObjectIdentifier) -> pkcs8::spki::Error {
+    pkcs8::spki::Error::Asn1(der::ErrorKind::OidUnknown { oid: found }.into())
+}
+
+/// `spki` error for explicit curve parameters.
+fn explicit_parameters() -> pkcs8::spki::Error {
+    pkcs8::spki::Error::Asn1(
+        der::ErrorKind::Value {
+            tag: der::Tag::Sequence,
+        }
+        .into(),
+    )
+}
+
+#[test]
+fn decode_pkcs8_private_key_wrong_curve() {
+    let err = SecretKey::from_pkcs8_der(P384_PKCS8_PRIVATE_KEY_DER).unwrap_err();
+    assert_eq!(err, pkcs8::Error::PublicKey(curve_mismatch(SECP384R1_OID)));
+}
+
+#[test]
+fn decode_pkcs8_public_key_wrong_curve() {
+    let err = PublicKey::from_public_key_der(P384_PKCS8_PUBLIC_KEY_DER).unwrap_err();
+    assert_eq!(err, curve_mismatch(SECP384R1_OID));
+}
+
+#[test]
+fn validate_algorithm_identifier_wrong_curve() {
+    let spki = pkcs8::SubjectPublicKeyInfoRef::try_from(P384_PKCS8_PUBLIC_KEY_DER).unwrap();
+    assert_eq!(
+        validate_algorithm_identifier::<MockCurve>(&spki.algorithm),
+        Err(ParametersError::CurveMismatch {
+            expected: PRIME256V1_OID,
+            found: SECP384R1_OID,
+        })
+    );
+
+    let private_key_info = pkcs8::PrivateKeyInfo::try_from(P384_PKCS8_PRIVATE_KEY_DER).unwrap();
+    assert_eq!(
+        validate_algorithm_identifier::<MockCurve>(&private_key_info.algorithm),
+        Err(ParametersError::CurveMismatch {
+            expected: PRIME256V1_OID,
+            found: SECP384R1_OID,
+        })
+    );
+}
+
+#[test]
+fn validate_algorithm_identifier_wrong_algorithm() {
+    let algorithm = pkcs8::AlgorithmIdentifierRef {
+        oid: RSA_ENCRYPTION_OID,
+        parameters: None,
+    };
+    let err = validate_algorithm_identifier::<MockCurve>(&algorithm).unwrap_err();
+    assert_eq!(
+        err,
+        ParametersError::AlgorithmMismatch {
+            expected: elliptic_curve::ALGORITHM_OID,
+            found: RSA_ENCRYPTION_OID,
+        }
+    );
+    assert_eq!(
+        pkcs8::spki::Error::from(err),
+        pkcs8::spki::Error::OidUnknown {
+            oid: RSA_ENCRYPTION_OID
+        }
+    );
+}
+
+#[test]
+fn validate_algorithm_identifier_missing_parameters() {
+    let algorithm = pkcs8::AlgorithmIdentifierRef {
+        oid: elliptic_curve::ALGORITHM_OID,
+        parameters: None,
+    };
+    let err = validate_algorithm_identifier::<MockCurve>(&algorithm).unwrap_err();
+    assert_eq!(err, ParametersError::ParametersMissing);
+    assert_eq!(
+        pkcs8::spki::Error::from(err),
+        pkcs8::spki::Error::AlgorithmParametersMissing
+    );
+}
+
+#[test]
+fn decode_pkcs8_private_key_explicit_params() {
+    let err = SecretKey::from_pkcs8_der(EXPLICIT_PARAMS_PKCS8_PRIVATE_KEY_DER).unwrap_err();
+    assert_eq!(err, pkcs8::Error::PublicKey(explicit_parameters()));
+}
+
+#[test]
+fn decode_pkcs8_public_key_explicit_params() {
+    let err = PublicKey::from_public_key_der(EXPLICIT_PARAMS_PKCS8_PUBLIC_KEY_DER).unwrap_err();
+    assert_eq!(err, explicit_parameters());
+
+    let spki =
+        pkcs8::SubjectPublicKeyInfoRef::try_from(EXPLICIT_PARAMS_PKCS8_PUBLIC_KEY_DER).unwrap();
+    assert_eq!(
+        validate_algorithm_identifier::<MockCurve>(&spki.algorithm),
+        Err(ParametersError::ExplicitParametersUnsupported)
+    );
+}
+
+#[test]
+fn encode_pkcs8_v1() {
+    let der = example_private_key();
+    let private_key_info = pkcs8::PrivateKeyInfo::try_from(der.as_bytes()).unwrap();
+    assert_eq!(private_key_info.version(), pkcs8::Version::V1);
+    assert_eq!(private_key_info.public_key, None);
+}
+
+#[test]
+fn pkcs8_v2_round_trip() {
+    let secret_key = SecretKey::from_slice(&EXAMPLE_SCALAR).unwrap();
+    let der = secret_key
+        .to_pkcs8_der_with_version(pkcs8::Version::V2)
+        .unwrap();
+
+    let private_key_info = pkcs8::PrivateKeyInfo::try_from(der.as_bytes()).unwrap();
+    assert_eq!(private_key_info.version(), pkcs8::Version::V2);
+    assert_eq!(
+        private_key_info.public_key,
+        Some(secret_key.public_key().to_encoded_point(false).as_bytes())
+    );
+
+    assert_eq!(
+        SecretKey::from_pkcs8_der(der.as_bytes()).unwrap(),
+        secret_key
+    );
+}
+
+#[test]
+fn decode_pkcs8_v2_public_key_mismatch() {
+    let secret_key = SecretKey::from_slice(&EXAMPLE_SCALAR).unwrap();
+    let other_public_key = SecretKey::from_slice(&[1; 32])
+        .unwrap()
+        .public_key()
+        .to_encoded_point(false);
+
+    let der = secret_key
+        .to_pkcs8_der_with_version(pkcs8::Version::V2)
+        .unwrap();
+    let mut private_key_info = pkcs8::PrivateKeyInfo::try_from(der.as_bytes()).unwrap();
+    private_key_info.public_key = Some(other_public_key.as_bytes());
+
+    let tampered = der::SecretDocument::encode_msg(&private_key_info).unwrap();
+    assert_eq!(
+        SecretKey::from_pkcs8_der(tampered.as_bytes()),
+        Err(pkcs8::Error::KeyMalformed)
+    );
+}
+
+#[test]
+fn write_pkcs8_der() {
+    let secret_key = SecretKey::from_slice(&EXAMPLE_SCALAR).unwrap();
+    let mut buf = [0u8; SecretKey::MAX_PKCS8_DER_LEN];
+    let der = secret_key.write_pkcs8_der(&mut buf).unwrap();
+    assert_eq!(der, example_private_key().as_bytes());
+    assert_eq!(der.len(), SecretKey::MAX_PKCS8_DER_LEN);
+}
+
+#[test]
+fn write_pkcs8_der_buffer_too_small() {
+    let secret_key = SecretKey::from_slice(&EXAMPLE_SCALAR).unwrap();
+    let mut buf = [0u8; SecretKey::MAX_PKCS8_DER_LEN - 1];
+    let err = secret_key.write_pkcs8_der(&mut buf).unwrap_err();
+    assert!(matches!(err, pkcs8::Error::Asn1(err) if err.kind() == der::ErrorKind::Overlength));
+}
+
+#[test]
+fn write_public_key_der() {
+    let public_key = PublicKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    let mut buf = [0u8; PublicKey::MAX_PUBLIC_KEY_DER_LEN];
+    let der = public_key.write_public_key_der(&mut buf).unwrap();
+    assert_eq!(der, public_key.to_public_key_der().unwrap().as_bytes());
+    assert_eq!(der, &PKCS8_PUBLIC_KEY_DER[..]);
+    assert_eq!(der.len(), PublicKey::MAX_PUBLIC_KEY_DER_LEN);
+}
+
+#[test]
+fn write_public_key_der_buffer_too_small() {
+    let public_key = PublicKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    let mut buf = [0u8; PublicKey::MAX_PUBLIC_KEY_DER_LEN - 1];
+    let err = public_key.write_public_key_der(&mut buf).unwrap_err();
+    assert!(
+        matches!(err, pkcs8::spki::Error::Asn1(err) if err.kind() == der::ErrorKind::Overlength)
+    );
+}
+
+/// SHA-256 fingerprint of [`PKCS8_PUBLIC_KEY_DER`] computed with
+/// `openssl pkey -pubin -outform DER | openssl dgst -sha256`.
+#[cfg(feature = "fingerprint")]
+const PKCS8_PUBLIC_KEY_SHA256: [u8; 32] =
+    hex!("b9968d56ed8d6aa3fb43b15fa01e355d7a3a0203b1408b3fd2733637c4d1642c");
+
+#[test]
+#[cfg(feature = "fingerprint")]
+fn public_key_fingerprint() {
+    use sha2::{Digest, Sha256};
+
+    let public_key = PublicKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    assert_eq!(
+        public_key.fingerprint::<Sha256>(),
+        Sha256::digest(public_key.to_public_key_der().unwrap().as_bytes())
+    );
+    assert_eq!(public_key.fingerprint_sha256(), PKCS8_PUBLIC_KEY_SHA256);
+    assert_eq!(
+        public_key.fingerprint_sha256_base64(),
+        "uZaNVu2NaqP7Q7FfoB41XXo6AgOxQIs/0nM2N8TRZCw="
+    );
+}
@@ -0,0 +1,235 @@
+//! `serde` tests
+
+#![cfg(all(feature = "dev", feature = "serde"))]
+
+use elliptic_curve::{
+    dev::{NonZeroScalar, PublicKey, ScalarPrimitive},
+    pkcs8::EncodePublicKey,
+    sec1::ToEncodedPoint,
+    PrimeField,
+};
+use hex_literal::hex;
+
+#[cfg(feature = "serde-secret")]
+use elliptic_curve::dev::SecretKey;
+
+const SCALAR_BYTES: [u8; 32] =
+    hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+
+const SCALAR_JSON: &str = "\"C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721\"";
+
+/// Mock curve order, i.e. the smallest out-of-range scalar.
+const ORDER_BYTES: [u8; 32] =
+    hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+
+fn scalar() -> ScalarPrimitive {
+    ScalarPrimitive::from_slice(&SCALAR_BYTES).unwrap()
+}
+
+#[test]
+fn scalar_primitive_json_round_trip() {
+    let json = serde_json::to_string(&scalar()).unwrap();
+    assert_eq!(json, SCALAR_JSON);
+    assert_eq!(
+        serde_json::from_str::<ScalarPrimitive>(&json).unwrap(),
+        scalar()
+    );
+}
+
+#[test]
+fn scalar_primitive_bincode_round_trip() {
+    let bytes = bincode::serialize(&scalar()).unwrap();
+    assert_eq!(bytes, bincode::serialize(&SCALAR_BYTES[..]).unwrap());
+    assert_eq!(
+        bincode::deserialize::<ScalarPrimitive>(&bytes).unwrap(),
+        scalar()
+    );
+}
+
+#[test]
+fn scalar_primitive_rejects_out_of_range() {
+    let json = "\"FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551\"";
+    assert!(serde_json::from_str::<ScalarPrimitive>(json).is_err());
+    let bytes = bincode::serialize(&ORDER_BYTES[..]).unwrap();
+    assert!(bincode::deserialize::<ScalarPrimitive>(&bytes).is_err());
+}
+
+#[test]
+fn scalar_primitive_rejects_wrong_length() {
+    assert!(serde_json::from_str::<ScalarPrimitive>("\"C9AFA9D8\"").is_err());
+    let bytes = bincode::serialize(&SCALAR_BYTES[..31]).unwrap();
+    assert!(bincode::deserialize::<ScalarPrimitive>(&bytes).is_err());
+}
+
+fn nonzero_scalar() -> NonZeroScalar {
+    NonZeroScalar::try_from(&SCALAR_BYTES[..]).unwrap()
+}
+
+#[test]
+fn nonzero_scalar_json_round_trip() {
+    let json = serde_json::to_string(&nonzero_scalar()).unwrap();
+    assert_eq!(json, SCALAR_JSON);
+    assert_eq!(
+        serde_json::from_str::<NonZeroScalar>(&json)
+            .unwrap()
+            .to_repr(),
+        nonzero_scalar().to_repr()
+    );
+}
+
+#[test]
+fn nonzero_scalar_bincode_round_trip() {
+    let bytes = bincode::serialize(&nonzero_scalar()).unwrap();
+    assert_eq!(bytes, bincode::serialize(&scalar()).unwrap());
+    assert_eq!(
+        bincode::deserialize::<NonZeroScalar>(&bytes)
+            .unwrap()
+            .to_repr(),
+        nonzero_scalar().to_repr()
+    );
+}
+
+#[test]
+fn nonzero_scalar_rejects_zero() {
+    let json = serde_json::to_string(&ScalarPrimitive::ZERO).unwrap();
+    let err = serde_json::from_str::<NonZeroScalar>(&json).err().unwrap();
+    assert!(err.to_string().contains("expected non-zero scalar"));
+
+    let bytes = bincode::serialize(&[0u8; 32][..]).unwrap();
+    assert!(bincode::deserialize::<NonZeroScalar>(&bytes).is_err());
+}
+
+#[test]
+fn nonzero_scalar_rejects_out_of_range() {
+    let bytes = bincode::serialize(&ORDER_BYTES[..]).unwrap();
+    assert!(bincode::deserialize::<NonZeroScalar>(&bytes).is_err());
+}
+
+#[cfg(feature = "serde-secret")]
+fn secret_key() -> SecretKey {
+    SecretKey::from_slice(&SCALAR_BYTES).unwrap()
+}
+
+#[test]
+#[cfg(feature = "serde-secret")]
+fn secret_key_json_round_trip() {
+    let json = serde_json::to_string(&secret_key()).unwrap();
+    assert_eq!(json, SCALAR_JSON);
+    assert_eq!(
+        serde_json::from_str::<SecretKey>(&json).unwrap(),
+        secret_key()
+    );
+}
+
+#[test]
+#[cfg(feature = "serde-secret")]
+fn secret_key_bincode_round_trip() {
+    let bytes = bincode::serialize(&secret_key()).unwrap();
+    assert_eq!(bytes, bincode::serialize(&SCALAR_BYTES[..]).unwrap());
+    assert_eq!(
+        bincode::deserialize::<SecretKey>(&bytes).unwrap(),
+        secret_key()
+    );
+}
+
+#[test]
+#[cfg(feature = "serde-secret")]
+fn secret_key_rejects_zero() {
+    let json = serde_json::to_string(&ScalarPrimitive::ZERO).unwrap();
+    assert!(serde_json::from_str::<SecretKey>(&json).is_err());
+
+    let bytes = bincode::serialize(&[0u8; 32][..]).unwrap();
+    assert!(bincode::deserialize::<SecretKey>(&bytes).is_err());
+}
+
+#[test]
+#[cfg(feature = "serde-secret")]
+fn secret_key_rejects_out_of_range() {
+    let bytes = bincode::serialize(&ORDER_BYTES[..]).unwrap();
+    assert!(bincode::deserialize::<SecretKey>(&bytes).is_err());
+}
+
+/// Uncompressed SEC1 encoding of the example public key.
+const PUBLIC_KEY_BYTES: [u8; 65] = hex!("041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F");
+
+/// Compressed SEC1 encoding of the example public key.
+const COMPRESSED_PUBLIC_KEY_BYTES: [u8; 33] =
+    hex!("031CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7F");
+
+fn public_key() -> PublicKey {
+    PublicKey::from_sec1_bytes(&PUBLIC_KEY_BYTES).unwrap()
+}
+
+#[test]
+fn public_key_json_round_trip() {
+    let json = serde_json::to_string(&public_key()).unwrap();
+    assert_eq!(json, format!("\"{}\"", hex_upper(&PUBLIC_KEY_BYTES)));
+    assert_eq!(
+        serde_json::from_str::<PublicKey>(&json).unwrap(),
+        public_key()
+    );
+}
+
+#[test]
+fn public_key_bincode_round_trip() {
+    let bytes = bincode::serialize(&public_key()).unwrap();
+    assert_eq!(bytes, bincode::serialize(&PUBLIC_KEY_BYTES[..]).unwrap());
+    assert_eq!(
+        bincode::deserialize::<PublicKey>(&bytes).unwrap(),
+        public_key()
+    );
+}
+
+#[test]
+fn public_key_deserialize_compressed() {
+    let json = format!("\"{}\"", hex_upper(&COMPRESSED_PUBLIC_KEY_BYTES));
+    let from_json = serde_json::from_str::<PublicKey>(&json).unwrap();
+    assert_eq!(
+        from_json.to_encoded_point(false).as_bytes(),
+        &PUBLIC_KEY_BYTES[..]
+    );
+
+    let bytes = bincode::serialize(&COMPRESSED_PUBLIC_KEY_BYTES[..]).unwrap();
+    let from_bincode = bincode::deserialize::<PublicKey>(&bytes).unwrap();
+    assert_eq!(
+        from_bincode.to_encoded_point(false).as_bytes(),
+        &PUBLIC_KEY_BYTES[..]
+    );
+}
+
+#[test]
+fn public_key_deserialize_spki() {
+    let der = public_key().to_public_key_der().unwrap();
+
+    let json = format!("\"{}\"", hex_upper(der.as_bytes()));
+    assert_eq!(
+        serde_json::from_str::<PublicKey>(&json).unwrap(),
+        public_key()
+    );
+
+    let bytes = bincode::serialize(der.as_bytes()).unwrap();
+    assert_eq!(
+        bincode::deserialize::<PublicKey>(&bytes).unwrap(),
+        public_key()
+    );
+}
+
+#[test]
+fn public_key_rejects_malformed() {
+    // Truncated point
+    let json = format!("\"{}\"", hex_upper(&PUBLIC_KEY_BYTES[..64]));
+    assert!(serde_json::from_str::<PublicKey>(&json).is_err());
+
+    // Invalid tag
+    let mut bytes = COMPRESSED_PUBLIC_KEY_BYTES;
+    bytes[0] = 0x08;
+    let bytes = bincode::serialize(&bytes[..]).unwrap();
+    assert!(bincode::deserialize::<PublicKey>(&bytes).is_err());
+
+    // Identity
+    assert!(serde_json::from_str::<PublicKey>("\"00\"").is_err());
+}
+
+fn hex_upper(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02X}")).collect()
+}